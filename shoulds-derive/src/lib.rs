@@ -0,0 +1,219 @@
+//! # shoulds-derive
+//!
+//! Derive macros for [`shoulds`](https://crates.io/crates/shoulds).
+//!
+//! This crate is not meant to be used directly; enable the `derive` feature of
+//! `shoulds` and use the re-exported `#[derive(ShouldDiff)]` instead.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Error, Expr, Fields, GenericParam, Ident,
+    Index, Member, Result,
+};
+
+/// Derives `shoulds::ShouldDiff`, comparing values field by field.
+///
+/// Field attributes:
+///
+/// - `#[should(skip)]` leaves the field out of the comparison.
+/// - `#[should(approx = 1e-6)]` compares floating point fields within the given tolerance.
+///
+/// Enums must also implement `Debug` so that mismatching variants can be reported.
+#[proc_macro_derive(ShouldDiff, attributes(should))]
+pub fn derive_should_diff(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+enum Comparison {
+    Skip,
+    Exact,
+    Approx(Expr),
+}
+
+fn expand(mut input: DeriveInput) -> Result<TokenStream2> {
+    for param in &mut input.generics.params {
+        if let GenericParam::Type(param) = param {
+            param.bounds.push(parse_quote!(::shoulds::ShouldDiff));
+        }
+    }
+
+    // Mismatching enum variants are reported with their `Debug` output.
+    if let Data::Enum(_) = &input.data {
+        input
+            .generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(Self: ::std::fmt::Debug));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let actual = bindings(&data.fields, "actual");
+            let expected = bindings(&data.fields, "expected");
+            let comparisons = compare_fields(&data.fields, &actual, &expected)?;
+            let actual_pattern = pattern(quote!(#name), &data.fields, &actual);
+            let expected_pattern = pattern(quote!(#name), &data.fields, &expected);
+
+            quote! {
+                let #actual_pattern = self;
+                let #expected_pattern = expected;
+                #(#comparisons)*
+            }
+        }
+        Data::Enum(data) => {
+            let mut arms = Vec::new();
+
+            for variant in &data.variants {
+                let variant_name = &variant.ident;
+                let actual = bindings(&variant.fields, "actual");
+                let expected = bindings(&variant.fields, "expected");
+                let comparisons = compare_fields(&variant.fields, &actual, &expected)?;
+                let actual_pattern =
+                    pattern(quote!(#name::#variant_name), &variant.fields, &actual);
+                let expected_pattern =
+                    pattern(quote!(#name::#variant_name), &variant.fields, &expected);
+
+                arms.push(quote! {
+                    (#actual_pattern, #expected_pattern) => {
+                        #(#comparisons)*
+                    }
+                });
+            }
+
+            quote! {
+                #[allow(unreachable_patterns)]
+                match (self, expected) {
+                    #(#arms)*
+                    _ => differences.push(::shoulds::Difference::new(
+                        path,
+                        ::std::format!("{:?}", expected),
+                        ::std::format!("{:?}", self),
+                    )),
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "ShouldDiff cannot be derived for unions",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::shoulds::ShouldDiff for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn diff(
+                &self,
+                expected: &Self,
+                path: &str,
+                differences: &mut ::std::vec::Vec<::shoulds::Difference>,
+            ) {
+                #body
+            }
+        }
+    })
+}
+
+fn bindings(fields: &Fields, prefix: &str) -> Vec<Ident> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => format_ident!("__{}_{}", prefix, ident),
+            None => format_ident!("__{}_{}", prefix, index),
+        })
+        .collect()
+}
+
+fn pattern(path: TokenStream2, fields: &Fields, bindings: &[Ident]) -> TokenStream2 {
+    let members = members(fields);
+
+    match fields {
+        Fields::Unit => quote!(#path),
+        _ => quote!(#path { #(#members: #bindings),* }),
+    }
+}
+
+fn members(fields: &Fields) -> Vec<Member> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index {
+                index: index as u32,
+                span: Span::call_site(),
+            }),
+        })
+        .collect()
+}
+
+fn compare_fields(
+    fields: &Fields,
+    actual: &[Ident],
+    expected: &[Ident],
+) -> Result<Vec<TokenStream2>> {
+    let mut comparisons = Vec::new();
+
+    for (((field, member), actual), expected) in
+        fields.iter().zip(members(fields)).zip(actual).zip(expected)
+    {
+        let segment = match &member {
+            Member::Named(ident) => ident.to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        };
+        let field_path = quote!(&::std::format!("{}.{}", path, #segment));
+
+        match comparison(field)? {
+            Comparison::Skip => {}
+            Comparison::Exact => comparisons.push(quote! {
+                ::shoulds::ShouldDiff::diff(#actual, #expected, #field_path, differences);
+            }),
+            Comparison::Approx(tolerance) => comparisons.push(quote! {
+                ::shoulds::ApproxDiff::diff_approx(
+                    #actual,
+                    #expected,
+                    #tolerance,
+                    #field_path,
+                    differences,
+                );
+            }),
+        }
+    }
+
+    Ok(comparisons)
+}
+
+fn comparison(field: &syn::Field) -> Result<Comparison> {
+    let mut comparison = Comparison::Exact;
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("should"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                comparison = Comparison::Skip;
+                Ok(())
+            } else if meta.path.is_ident("approx") {
+                comparison = Comparison::Approx(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `skip` or `approx = <tolerance>`"))
+            }
+        })?;
+    }
+
+    Ok(comparison)
+}
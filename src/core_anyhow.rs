@@ -0,0 +1,6 @@
+use crate::core_error::impl_report_assertions;
+
+impl_report_assertions!(anyhow::Error, Context, context);
+
+#[cfg(test)]
+crate::core_error::report_assertion_tests!(anyhow::Error, Context, context, anyhow);
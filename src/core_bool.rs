@@ -14,7 +14,9 @@ impl<'a> Should<'a, bool> {
     /// # Panics
     /// Panics if the actual value is not true.
     pub fn be_true(&self) {
-        assert!(*self.actual(), "Expected {:?} to be true", self.actual());
+        self.check(*self.actual(), || {
+            format!("Expected {:?} to be true", self.actual())
+        });
     }
 
     /// Asserts that the value is false.
@@ -30,7 +32,9 @@ impl<'a> Should<'a, bool> {
     /// # Panics
     /// Panics if the actual value is not false.
     pub fn be_false(&self) {
-        assert!(!*self.actual(), "Expected {:?} to be false", self.actual());
+        self.check(!*self.actual(), || {
+            format!("Expected {:?} to be false", self.actual())
+        });
     }
 }
 
@@ -121,12 +125,14 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::useless_vec)]
     fn bool_from_comparison_with_be_true() {
         let vec = vec![1, 2, 3];
         (vec.len() == 3).should().be_true();
     }
 
     #[test]
+    #[allow(clippy::useless_vec, clippy::len_zero)]
     fn bool_from_comparison_with_be_false() {
         let vec = vec![1, 2, 3];
         (vec.len() == 0).should().be_false();
@@ -0,0 +1,359 @@
+use crate::core_time::{format_duration, format_signed_duration, CloseTo};
+use crate::Should;
+use chrono::{DateTime, Datelike, FixedOffset, Offset, SecondsFormat, TimeZone, Weekday};
+use std::fmt::Display;
+use std::time::Duration;
+
+/// Formats a date-time as an ISO-8601 timestamp, using `Z` for UTC.
+fn format_date_time<Tz>(time: &DateTime<Tz>) -> String
+where
+    Tz: TimeZone,
+    Tz::Offset: Display,
+{
+    time.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+/// Returns the signed difference `actual - expected` for failure messages.
+fn signed_delta<Tz, Tz2>(actual: &DateTime<Tz>, expected: &DateTime<Tz2>) -> (bool, Duration)
+where
+    Tz: TimeZone,
+    Tz2: TimeZone,
+{
+    let delta = actual.clone().signed_duration_since(expected.clone());
+    let magnitude = delta.abs().to_std().unwrap_or(Duration::MAX);
+
+    (delta < chrono::TimeDelta::zero(), magnitude)
+}
+
+/// Returns the full English name of a weekday.
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+impl<'a, Tz> Should<'a, DateTime<Tz>>
+where
+    Tz: TimeZone,
+    Tz::Offset: Display,
+{
+    /// Asserts that the date-time is strictly before `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use shoulds::Shouldable;
+    ///
+    /// let created = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+    /// let updated = Utc.with_ymd_and_hms(2024, 5, 1, 12, 30, 0).unwrap();
+    /// created.should().be_before(updated);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the date-time is at or after `other`, printing both as
+    /// ISO-8601 along with the signed difference.
+    #[track_caller]
+    pub fn be_before<Tz2>(&self, other: DateTime<Tz2>)
+    where
+        Tz2: TimeZone,
+        Tz2::Offset: Display,
+    {
+        self.check(*self.actual() < other, || {
+            let (negative, delta) = signed_delta(self.actual(), &other);
+            format!(
+                "Expected {} to be before {}, but the difference was {}",
+                format_date_time(self.actual()),
+                format_date_time(&other),
+                format_signed_duration(negative, delta)
+            )
+        });
+    }
+
+    /// Asserts that the date-time is strictly after `other`.
+    ///
+    /// # Panics
+    /// Panics if the date-time is at or before `other`, printing both as
+    /// ISO-8601 along with the signed difference.
+    #[track_caller]
+    pub fn be_after<Tz2>(&self, other: DateTime<Tz2>)
+    where
+        Tz2: TimeZone,
+        Tz2::Offset: Display,
+    {
+        self.check(*self.actual() > other, || {
+            let (negative, delta) = signed_delta(self.actual(), &other);
+            format!(
+                "Expected {} to be after {}, but the difference was {}",
+                format_date_time(self.actual()),
+                format_date_time(&other),
+                format_signed_duration(negative, delta)
+            )
+        });
+    }
+
+    /// Asserts that the date-time falls on the same calendar day as `other`,
+    /// in the date-time's own timezone.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use shoulds::Shouldable;
+    ///
+    /// let morning = Utc.with_ymd_and_hms(2024, 5, 1, 8, 0, 0).unwrap();
+    /// let evening = Utc.with_ymd_and_hms(2024, 5, 1, 20, 0, 0).unwrap();
+    /// morning.should().be_same_day_as(evening);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the days differ, printing both dates.
+    #[track_caller]
+    pub fn be_same_day_as<Tz2>(&self, other: DateTime<Tz2>)
+    where
+        Tz2: TimeZone,
+        Tz2::Offset: Display,
+    {
+        let actual = self.actual().date_naive();
+        let expected = other.with_timezone(&self.actual().timezone()).date_naive();
+
+        self.check(actual == expected, || {
+            format!(
+                "Expected {} to be on the same day as {}, but it was on {} instead of {}",
+                format_date_time(self.actual()),
+                format_date_time(&other),
+                actual,
+                expected
+            )
+        });
+    }
+
+    /// Asserts that the date-time falls on `expected`, in its own timezone.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::{TimeZone, Utc, Weekday};
+    /// use shoulds::Shouldable;
+    ///
+    /// let release = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+    /// release.should().be_weekday(Weekday::Wed);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the date-time falls on another weekday.
+    #[track_caller]
+    pub fn be_weekday(&self, expected: Weekday) {
+        let actual = self.actual().weekday();
+
+        self.check(actual == expected, || {
+            format!(
+                "Expected {} to be on a {}, but it was on a {}",
+                format_date_time(self.actual()),
+                weekday_name(expected),
+                weekday_name(actual)
+            )
+        });
+    }
+
+    /// Asserts that the date-time has the UTC offset `expected`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    /// use shoulds::Shouldable;
+    ///
+    /// let oslo = FixedOffset::east_opt(2 * 3600).unwrap();
+    /// let meeting = oslo.with_ymd_and_hms(2024, 5, 1, 9, 0, 0).unwrap();
+    /// meeting.should().be_in_timezone(oslo);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the offset differs, printing both offsets.
+    #[track_caller]
+    pub fn be_in_timezone(&self, expected: FixedOffset) {
+        let actual = self.actual().offset().fix();
+
+        self.check(actual == expected, || {
+            format!(
+                "Expected {} to be in timezone {}, but its offset was {}",
+                format_date_time(self.actual()),
+                expected,
+                actual
+            )
+        });
+    }
+
+    /// Starts asserting that the date-time is close to `expected`; complete
+    /// the assertion with [`CloseTo::within`].
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use shoulds::Shouldable;
+    /// use std::time::Duration;
+    ///
+    /// let sent = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 1).unwrap();
+    /// let received = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+    /// sent.should().be_close_to(received).within(Duration::from_secs(2));
+    /// ```
+    pub fn be_close_to(&self, expected: DateTime<Tz>) -> CloseTo<'_, 'a, DateTime<Tz>> {
+        CloseTo {
+            should: self,
+            expected,
+        }
+    }
+}
+
+impl<Tz> CloseTo<'_, '_, DateTime<Tz>>
+where
+    Tz: TimeZone,
+    Tz::Offset: Display,
+{
+    /// Asserts that the date-time differs from the expected one by at most
+    /// `tolerance`.
+    ///
+    /// # Panics
+    /// Panics if the difference is larger, printing both as ISO-8601 along
+    /// with the signed difference.
+    #[track_caller]
+    pub fn within(&self, tolerance: Duration) {
+        let (negative, delta) = signed_delta(self.should.actual(), &self.expected);
+
+        self.should.check(delta <= tolerance, || {
+            format!(
+                "Expected {} to be within {} of {}, but the difference was {}",
+                format_date_time(self.should.actual()),
+                format_duration(tolerance),
+                format_date_time(&self.expected),
+                format_signed_duration(negative, delta)
+            )
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Shouldable;
+    use chrono::{DateTime, FixedOffset, TimeZone, Utc, Weekday};
+    use std::time::Duration;
+
+    fn noon() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap()
+    }
+
+    fn oslo() -> FixedOffset {
+        FixedOffset::east_opt(2 * 3600).unwrap()
+    }
+
+    #[test]
+    fn be_before_and_after_success_should_not_panic() {
+        let later = noon() + chrono::TimeDelta::seconds(1);
+
+        noon().should().be_before(later);
+        later.should().be_after(noon());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected 2024-05-01T12:00:01.500Z to be before 2024-05-01T12:00:00Z, but the difference was +1.5s"
+    )]
+    fn be_before_fail_should_panic() {
+        (noon() + chrono::TimeDelta::milliseconds(1_500))
+            .should()
+            .be_before(noon());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected 2024-05-01T11:58:00Z to be after 2024-05-01T12:00:00Z, but the difference was -2m"
+    )]
+    fn be_after_fail_should_panic() {
+        (noon() - chrono::TimeDelta::minutes(2))
+            .should()
+            .be_after(noon());
+    }
+
+    #[test]
+    fn be_before_across_timezones_should_not_panic() {
+        let in_oslo = oslo().with_ymd_and_hms(2024, 5, 1, 13, 0, 0).unwrap();
+        in_oslo.should().be_before(noon());
+    }
+
+    #[test]
+    fn be_same_day_as_success_should_not_panic() {
+        noon()
+            .should()
+            .be_same_day_as(Utc.with_ymd_and_hms(2024, 5, 1, 23, 59, 59).unwrap());
+    }
+
+    #[test]
+    fn be_same_day_as_should_use_own_timezone() {
+        let late = Utc.with_ymd_and_hms(2024, 5, 1, 23, 30, 0).unwrap();
+        let early_in_oslo = oslo().with_ymd_and_hms(2024, 5, 2, 1, 0, 0).unwrap();
+
+        late.should().be_same_day_as(early_in_oslo);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected 2024-05-01T12:00:00Z to be on the same day as 2024-05-03T01:00:00+02:00, but it was on 2024-05-01 instead of 2024-05-02"
+    )]
+    fn be_same_day_as_fail_should_panic() {
+        noon()
+            .should()
+            .be_same_day_as(oslo().with_ymd_and_hms(2024, 5, 3, 1, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn be_weekday_success_should_not_panic() {
+        noon().should().be_weekday(Weekday::Wed);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "release: Expected 2024-05-01T12:00:00Z to be on a Friday, but it was on a Wednesday"
+    )]
+    fn be_weekday_fail_should_panic_with_path() {
+        noon().should().named("release").be_weekday(Weekday::Fri);
+    }
+
+    #[test]
+    fn be_in_timezone_success_should_not_panic() {
+        oslo()
+            .with_ymd_and_hms(2024, 5, 1, 9, 0, 0)
+            .unwrap()
+            .should()
+            .be_in_timezone(oslo());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected 2024-05-01T12:00:00Z to be in timezone +02:00, but its offset was +00:00"
+    )]
+    fn be_in_timezone_fail_should_panic() {
+        noon().fixed_offset().should().be_in_timezone(oslo());
+    }
+
+    #[test]
+    fn be_close_to_success_should_not_panic() {
+        (noon() - chrono::TimeDelta::seconds(1))
+            .should()
+            .be_close_to(noon())
+            .within(Duration::from_secs(1));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected 2024-05-01T12:00:03Z to be within 1s of 2024-05-01T12:00:00Z, but the difference was +3s"
+    )]
+    fn be_close_to_fail_should_panic() {
+        (noon() + chrono::TimeDelta::seconds(3))
+            .should()
+            .be_close_to(noon())
+            .within(Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,518 @@
+use crate::Should;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+
+/// A single mismatch found while comparing two values field by field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Difference {
+    /// The path to the mismatching part of the value, e.g. `.b.items[3].price`.
+    pub path: String,
+    /// The `Debug` representation of the expected value.
+    pub expected: String,
+    /// The `Debug` representation of the actual value.
+    pub actual: String,
+}
+
+impl Difference {
+    pub fn new(path: &str, expected: String, actual: String) -> Self {
+        Difference {
+            path: path.to_string(),
+            expected,
+            actual,
+        }
+    }
+}
+
+impl Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "expected {} but was {}", self.expected, self.actual)
+        } else {
+            write!(
+                f,
+                "{}: expected {} but was {}",
+                self.path, self.expected, self.actual
+            )
+        }
+    }
+}
+
+/// Structural comparison that reports every mismatching field path.
+///
+/// Implemented for common standard library types and derivable with
+/// `#[derive(ShouldDiff)]` when the `derive` feature is enabled.
+pub trait ShouldDiff {
+    /// Compares `self` against `expected`, pushing a [`Difference`] for every
+    /// mismatch found below `path`.
+    fn diff(&self, expected: &Self, path: &str, differences: &mut Vec<Difference>);
+}
+
+/// Comparison of floating point values within a tolerance.
+///
+/// Used by `#[should(approx = ...)]` fields of `#[derive(ShouldDiff)]`.
+pub trait ApproxDiff {
+    /// Compares `self` against `expected`, pushing a [`Difference`] when they
+    /// differ by more than `tolerance`.
+    fn diff_approx(
+        &self,
+        expected: &Self,
+        tolerance: f64,
+        path: &str,
+        differences: &mut Vec<Difference>,
+    );
+}
+
+macro_rules! impl_leaf_diff {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl ShouldDiff for $ty {
+                fn diff(&self, expected: &Self, path: &str, differences: &mut Vec<Difference>) {
+                    if self != expected {
+                        differences.push(Difference::new(
+                            path,
+                            format!("{:?}", expected),
+                            format!("{:?}", self),
+                        ));
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_leaf_diff!(
+    bool,
+    char,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    f32,
+    f64,
+    str,
+    String,
+    ()
+);
+
+macro_rules! impl_approx_diff {
+    ($($ty:ty),*) => {
+        $(
+            impl ApproxDiff for $ty {
+                fn diff_approx(
+                    &self,
+                    expected: &Self,
+                    tolerance: f64,
+                    path: &str,
+                    differences: &mut Vec<Difference>,
+                ) {
+                    if (*self as f64 - *expected as f64).abs() > tolerance {
+                        differences.push(Difference::new(
+                            path,
+                            format!("{:?} ± {:?}", expected, tolerance),
+                            format!("{:?}", self),
+                        ));
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_diff!(f32, f64);
+
+impl<T: ApproxDiff + Debug> ApproxDiff for Option<T> {
+    fn diff_approx(
+        &self,
+        expected: &Self,
+        tolerance: f64,
+        path: &str,
+        differences: &mut Vec<Difference>,
+    ) {
+        match (self, expected) {
+            (Some(actual), Some(expected)) => {
+                actual.diff_approx(expected, tolerance, path, differences)
+            }
+            (None, None) => {}
+            _ => differences.push(Difference::new(
+                path,
+                format!("{:?}", expected),
+                format!("{:?}", self),
+            )),
+        }
+    }
+}
+
+impl<T: ApproxDiff> ApproxDiff for Vec<T> {
+    fn diff_approx(
+        &self,
+        expected: &Self,
+        tolerance: f64,
+        path: &str,
+        differences: &mut Vec<Difference>,
+    ) {
+        if self.len() != expected.len() {
+            differences.push(Difference::new(
+                &format!("{}.len()", path),
+                expected.len().to_string(),
+                self.len().to_string(),
+            ));
+        }
+
+        for (index, (actual, expected)) in self.iter().zip(expected).enumerate() {
+            actual.diff_approx(
+                expected,
+                tolerance,
+                &format!("{}[{}]", path, index),
+                differences,
+            );
+        }
+    }
+}
+
+impl<T: ShouldDiff + ?Sized> ShouldDiff for &T {
+    fn diff(&self, expected: &Self, path: &str, differences: &mut Vec<Difference>) {
+        (**self).diff(*expected, path, differences)
+    }
+}
+
+impl<T: ShouldDiff + ?Sized> ShouldDiff for Box<T> {
+    fn diff(&self, expected: &Self, path: &str, differences: &mut Vec<Difference>) {
+        (**self).diff(expected, path, differences)
+    }
+}
+
+impl<T: ShouldDiff + Debug> ShouldDiff for Option<T> {
+    fn diff(&self, expected: &Self, path: &str, differences: &mut Vec<Difference>) {
+        match (self, expected) {
+            (Some(actual), Some(expected)) => actual.diff(expected, path, differences),
+            (None, None) => {}
+            _ => differences.push(Difference::new(
+                path,
+                format!("{:?}", expected),
+                format!("{:?}", self),
+            )),
+        }
+    }
+}
+
+impl<T: ShouldDiff + Debug, E: ShouldDiff + Debug> ShouldDiff for Result<T, E> {
+    fn diff(&self, expected: &Self, path: &str, differences: &mut Vec<Difference>) {
+        match (self, expected) {
+            (Ok(actual), Ok(expected)) => actual.diff(expected, path, differences),
+            (Err(actual), Err(expected)) => actual.diff(expected, path, differences),
+            _ => differences.push(Difference::new(
+                path,
+                format!("{:?}", expected),
+                format!("{:?}", self),
+            )),
+        }
+    }
+}
+
+impl<T: ShouldDiff> ShouldDiff for [T] {
+    fn diff(&self, expected: &Self, path: &str, differences: &mut Vec<Difference>) {
+        if self.len() != expected.len() {
+            differences.push(Difference::new(
+                &format!("{}.len()", path),
+                expected.len().to_string(),
+                self.len().to_string(),
+            ));
+        }
+
+        for (index, (actual, expected)) in self.iter().zip(expected).enumerate() {
+            actual.diff(expected, &format!("{}[{}]", path, index), differences);
+        }
+    }
+}
+
+impl<T: ShouldDiff> ShouldDiff for Vec<T> {
+    fn diff(&self, expected: &Self, path: &str, differences: &mut Vec<Difference>) {
+        self.as_slice().diff(expected, path, differences)
+    }
+}
+
+impl<T: ShouldDiff, const N: usize> ShouldDiff for [T; N] {
+    fn diff(&self, expected: &Self, path: &str, differences: &mut Vec<Difference>) {
+        self.as_slice().diff(expected, path, differences)
+    }
+}
+
+fn diff_maps<'a, K, V>(
+    actual: impl Iterator<Item = (&'a K, &'a V)>,
+    get_actual: impl Fn(&K) -> Option<&'a V>,
+    expected: impl Iterator<Item = (&'a K, &'a V)>,
+    get_expected: impl Fn(&K) -> Option<&'a V>,
+    path: &str,
+    differences: &mut Vec<Difference>,
+) where
+    K: Debug + 'a,
+    V: ShouldDiff + Debug + 'a,
+{
+    for (key, expected) in expected {
+        let entry_path = format!("{}[{:?}]", path, key);
+
+        match get_actual(key) {
+            Some(actual) => actual.diff(expected, &entry_path, differences),
+            None => differences.push(Difference::new(
+                &entry_path,
+                format!("{:?}", expected),
+                "missing".to_string(),
+            )),
+        }
+    }
+
+    for (key, actual) in actual {
+        if get_expected(key).is_none() {
+            differences.push(Difference::new(
+                &format!("{}[{:?}]", path, key),
+                "missing".to_string(),
+                format!("{:?}", actual),
+            ));
+        }
+    }
+}
+
+impl<K, V> ShouldDiff for BTreeMap<K, V>
+where
+    K: Ord + Debug,
+    V: ShouldDiff + Debug,
+{
+    fn diff(&self, expected: &Self, path: &str, differences: &mut Vec<Difference>) {
+        diff_maps(
+            self.iter(),
+            |key| self.get(key),
+            expected.iter(),
+            |key| expected.get(key),
+            path,
+            differences,
+        )
+    }
+}
+
+impl<K, V> ShouldDiff for HashMap<K, V>
+where
+    K: Eq + Hash + Debug,
+    V: ShouldDiff + Debug,
+{
+    fn diff(&self, expected: &Self, path: &str, differences: &mut Vec<Difference>) {
+        // Sorted by the keys' `Debug` output, so differences are reported in a
+        // stable order even for keys that are not `Ord`.
+        let mut actual: Vec<_> = self.iter().collect();
+        let mut expected_entries: Vec<_> = expected.iter().collect();
+        actual.sort_by_cached_key(|(key, _)| format!("{:?}", key));
+        expected_entries.sort_by_cached_key(|(key, _)| format!("{:?}", key));
+
+        diff_maps(
+            actual.into_iter(),
+            |key| self.get(key),
+            expected_entries.into_iter(),
+            |key| expected.get(key),
+            path,
+            differences,
+        )
+    }
+}
+
+impl<'a, T> Should<'a, T>
+where
+    T: ShouldDiff + ?Sized,
+{
+    /// Asserts that the value is structurally equal to the expected value,
+    /// reporting every mismatching field path.
+    ///
+    /// Structs and enums opt in with `#[derive(ShouldDiff)]` from the `derive` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// let value = vec![10, 20];
+    /// value.should().eq_by_fields(&vec![10, 20]);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if any field differs, listing each difference on its own line,
+    /// e.g. `.b.items[3].price: expected 10 but was 12`.
+    pub fn eq_by_fields(&self, expected: &T) {
+        let mut differences = Vec::new();
+        self.actual()
+            .diff(expected, self.path().unwrap_or(""), &mut differences);
+
+        self.check(differences.is_empty(), || {
+            let lines: Vec<String> = differences
+                .iter()
+                .map(|difference| format!("  {}", difference))
+                .collect();
+
+            format!(
+                "Expected values to be equal by fields, but found {} difference(s):\n{}",
+                differences.len(),
+                lines.join("\n")
+            )
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Shouldable;
+    use shoulds_derive::ShouldDiff;
+    use std::collections::HashMap;
+
+    #[derive(ShouldDiff, Debug)]
+    struct Item {
+        price: u32,
+        #[should(skip)]
+        id: u64,
+    }
+
+    #[derive(ShouldDiff, Debug)]
+    struct Basket {
+        items: Vec<Item>,
+    }
+
+    #[derive(ShouldDiff, Debug)]
+    struct Order {
+        a: i32,
+        b: Basket,
+    }
+
+    #[derive(ShouldDiff, Debug)]
+    struct Point(#[should(approx = 1e-6)] f64, #[should(approx = 1e-6)] f64);
+
+    #[derive(ShouldDiff, Debug)]
+    enum Shape {
+        Circle { radius: u32 },
+        Square(u32),
+        Empty,
+    }
+
+    #[derive(ShouldDiff, Debug)]
+    enum Tagged<T> {
+        Value(T),
+        Missing,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    struct Key(&'static str);
+
+    fn order(price: u32) -> Order {
+        Order {
+            a: 1,
+            b: Basket {
+                items: vec![
+                    Item { price: 1, id: 1 },
+                    Item { price: 2, id: 2 },
+                    Item { price: 3, id: 3 },
+                    Item { price, id: 4 },
+                ],
+            },
+        }
+    }
+
+    #[test]
+    fn eq_by_fields_success_should_not_panic() {
+        order(10).should().eq_by_fields(&order(10));
+    }
+
+    #[test]
+    #[should_panic(expected = ".b.items[3].price: expected 10 but was 12")]
+    fn eq_by_fields_fail_should_panic_with_field_path() {
+        order(12).should().eq_by_fields(&order(10));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "found 2 difference(s):\n  .a: expected 2 but was 1\n  .b.items.len()"
+    )]
+    fn eq_by_fields_should_report_every_difference() {
+        let mut expected = order(3);
+        expected.a = 2;
+        expected.b.items.pop();
+
+        order(3).should().eq_by_fields(&expected);
+    }
+
+    #[test]
+    fn eq_by_fields_should_ignore_skipped_fields() {
+        let value = Item { price: 1, id: 1 };
+        value.should().eq_by_fields(&Item { price: 1, id: 2 });
+    }
+
+    #[test]
+    fn eq_by_fields_approx_success_should_not_panic() {
+        let value = Point(0.1 + 0.2, 1.0);
+        value.should().eq_by_fields(&Point(0.3, 1.0));
+    }
+
+    #[test]
+    #[should_panic(expected = ".1: expected 1.0 ± 1e-6 but was 1.1")]
+    fn eq_by_fields_approx_fail_should_panic() {
+        let value = Point(0.3, 1.1);
+        value.should().eq_by_fields(&Point(0.3, 1.0));
+    }
+
+    #[test]
+    #[should_panic(expected = ".radius: expected 2 but was 1")]
+    fn eq_by_fields_enum_same_variant_should_compare_fields() {
+        let value = Shape::Circle { radius: 1 };
+        value.should().eq_by_fields(&Shape::Circle { radius: 2 });
+    }
+
+    #[test]
+    #[should_panic(expected = "expected Empty but was Square(1)")]
+    fn eq_by_fields_enum_different_variant_should_panic() {
+        let value = Shape::Square(1);
+        value.should().eq_by_fields(&Shape::Empty);
+    }
+
+    #[test]
+    #[should_panic(expected = ".0.radius: expected 2 but was 1")]
+    fn eq_by_fields_generic_enum_should_compare_fields() {
+        let value = Tagged::Value(Shape::Circle { radius: 1 });
+        value
+            .should()
+            .eq_by_fields(&Tagged::Value(Shape::Circle { radius: 2 }));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected Missing but was Value(1)")]
+    fn eq_by_fields_generic_enum_different_variant_should_panic() {
+        Tagged::Value(1).should().eq_by_fields(&Tagged::Missing);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "found 2 difference(s):\n  [Key(\"a\")]: expected 1 but was 2\n  [Key(\"c\")]: expected missing but was 3"
+    )]
+    fn eq_by_fields_map_should_support_unordered_keys() {
+        let value = HashMap::from([(Key("c"), 3), (Key("a"), 2), (Key("b"), 2)]);
+        value
+            .should()
+            .eq_by_fields(&HashMap::from([(Key("b"), 2), (Key("a"), 1)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "[\"b\"]: expected 2 but was 3")]
+    fn eq_by_fields_map_should_report_key_path() {
+        let value = HashMap::from([("a", 1), ("b", 3)]);
+        value
+            .should()
+            .eq_by_fields(&HashMap::from([("a", 1), ("b", 2)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "order.b.items[3].price: expected 10 but was 12")]
+    fn eq_by_fields_should_include_should_path() {
+        order(12).should().named("order").eq_by_fields(&order(10));
+    }
+}
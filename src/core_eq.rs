@@ -1,100 +1,260 @@
+use crate::core_redact::redact;
+use crate::core_text_diff::line_diff;
+use crate::matchers::{self, Matcher};
 use crate::Should;
 use std::fmt::Debug;
 
+/// Describes how two values that print over several lines differ, as a line
+/// diff of their normalized and redacted `{:#?}` output.
+fn describe_diff<T: Debug + ?Sized, U: Debug + ?Sized>(actual: &T, expected: &U) -> String {
+    let actual = redact(&format!("{:#?}", actual));
+    let expected = redact(&format!("{:#?}", expected));
+
+    if (actual.contains('\n') || expected.contains('\n')) && actual != expected {
+        format!(
+            "\nDiff (- expected, + actual):\n{}",
+            line_diff(&expected, &actual)
+        )
+    } else {
+        String::new()
+    }
+}
+
+/// An expected value for [`Should::eq`] and [`Should::ne`], passed either by
+/// reference or by value.
+///
+/// This plays the role of [`Borrow`](std::borrow::Borrow), but without its
+/// blanket `impl<T> Borrow<T> for T`: with that impl, a `&str` could stand for
+/// both `str` and `&str`, so `name.should().eq("Hello")` on a `String` would
+/// no longer compile without type annotations.
+///
+/// References to any type are accepted, as are owned `i32`, `f64`, `bool`,
+/// `char`, `String` and `Option<T>`. Other numeric types, and collections
+/// such as `Vec<T>` that compare element types loosely, are left out on
+/// purpose: an unsuffixed literal passed by value would have no single type
+/// to infer, so `len.should().eq(3)` on a `usize` would stop compiling as soon
+/// as another crate adds a `PartialEq` impl for `usize`. Pass those by
+/// reference, as in `len.should().eq(&3)`. Implement `Expected<Self>` to pass
+/// other types by value.
+///
+/// # Examples
+/// ```
+/// use shoulds::{Expected, Shouldable};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Port(u16);
+///
+/// impl Expected<Port> for Port {
+///     fn expected(&self) -> &Port {
+///         self
+///     }
+/// }
+///
+/// Port(8080).should().eq(Port(8080));
+/// ```
+pub trait Expected<U: ?Sized> {
+    /// Returns the value to compare with.
+    fn expected(&self) -> &U;
+}
+
+impl<U: ?Sized> Expected<U> for &U {
+    fn expected(&self) -> &U {
+        self
+    }
+}
+
+/// Implements [`Expected`] for owned values of the given types.
+macro_rules! impl_expected_by_value {
+    ($($ty:ty),*) => {
+        $(
+            impl Expected<$ty> for $ty {
+                fn expected(&self) -> &$ty {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_expected_by_value!(i32, f64, bool, char, String);
+
+impl<V> Expected<Option<V>> for Option<V> {
+    fn expected(&self) -> &Option<V> {
+        self
+    }
+}
+
 impl<'a, T> Should<'a, T>
 where
-    T: PartialEq + Debug,
+    T: Debug + ?Sized,
 {
     /// Asserts that the value is equal to the expected value.
     ///
+    /// The expected value can be of any type the actual value can be compared
+    /// with, so a `String` can be compared with a `&str` literal and a `Vec<u8>`
+    /// with a byte string. It can be passed by reference, or by value for the
+    /// types implementing [`Expected`].
+    ///
     /// # Examples
     /// ```
     /// use shoulds::Shouldable;
     ///
     /// let value = 42;
     /// value.should().eq(&42);
+    /// value.should().eq(42);
+    ///
+    /// let name = "Hello".to_string();
+    /// name.should().eq("Hello");
+    /// name.should().eq("Hello".to_string());
+    ///
+    /// let bytes = b"abc".to_vec();
+    /// bytes.should().eq(b"abc");
     /// ```
     ///
     /// # Panics
-    /// Panics if the actual value is not equal to the expected value.
-    pub fn eq(&self, expected: &T) {
-        assert_eq!(
-            self.actual(),
-            expected,
-            "Expected {:?} to equal to {:?}",
-            self.actual(),
-            expected
-        );
+    /// Panics if the actual value is not equal to the expected value. Values
+    /// that print over several lines are followed by a line diff, with any
+    /// bound [`Redactions`](crate::Redactions) applied.
+    pub fn eq<E, U>(&self, expected: E)
+    where
+        E: Expected<U>,
+        T: PartialEq<U>,
+        U: Debug + ?Sized,
+    {
+        let expected = expected.expected();
+
+        self.check(matchers::eq(expected).check(&self.actual()), || {
+            format!(
+                "Expected {:?} to equal to {:?}{}",
+                self.actual(),
+                expected,
+                describe_diff(self.actual(), expected)
+            )
+        });
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::Shouldable;
+    use crate::{Redactions, Shouldable};
 
     #[test]
     fn eq_i32_success_should_not_panic() {
         let value = 42;
-        value.should().eq(&42);
+        value.should().eq(42);
     }
 
     #[test]
     #[should_panic(expected = "Expected 42 to equal to 43")]
     fn eq_i32_fail_should_panic() {
         let value = 42;
-        value.should().eq(&43);
+        value.should().eq(43);
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn eq_f64_success_should_not_panic() {
         let value = 3.14;
-        value.should().eq(&3.14);
+        value.should().eq(3.14);
     }
 
     #[test]
     #[should_panic(expected = "Expected 3.14 to equal to 2.71")]
+    #[allow(clippy::approx_constant)]
     fn eq_f64_fail_should_panic() {
         let value = 3.14;
-        value.should().eq(&2.71);
+        value.should().eq(2.71);
     }
 
     #[test]
     fn eq_string_success_should_not_panic() {
         let value = "Hello".to_string();
-        value.should().eq(&"Hello".to_string());
+        value.should().eq("Hello".to_string());
     }
 
     #[test]
     #[should_panic(expected = "Expected \"Hello\" to equal to \"World\"")]
     fn eq_string_fail_should_panic() {
         let value = "Hello".to_string();
-        value.should().eq(&"World".to_string());
+        value.should().eq("World".to_string());
+    }
+
+    #[test]
+    fn eq_string_str_success_should_not_panic() {
+        let value = "Hello".to_string();
+        value.should().eq("Hello");
+        value.should().eq(&"Hello");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected \"Hello\" to equal to \"World\"")]
+    fn eq_string_str_fail_should_panic() {
+        let value = "Hello".to_string();
+        value.should().eq("World");
+    }
+
+    #[test]
+    fn eq_bytes_success_should_not_panic() {
+        let value = b"abc".to_vec();
+        value.should().eq(b"abc");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected [97, 98, 99] to equal to [97, 98, 100]")]
+    fn eq_bytes_fail_should_panic() {
+        let value = b"abc".to_vec();
+        value.should().eq(b"abd");
+    }
+
+    #[test]
+    fn eq_vector_array_success_should_not_panic() {
+        let value = vec![1, 2, 3];
+        value.should().eq(&[1, 2, 3]);
+    }
+
+    #[test]
+    fn eq_by_value_success_should_not_panic() {
+        let count = 7;
+        count.should().eq(7);
+
+        let name = "Hello".to_string();
+        name.should().eq("Hello".to_string());
+
+        let maybe: Option<u64> = Some(3);
+        maybe.should().eq(Some(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 1.5 to equal to 2.5")]
+    fn eq_by_value_fail_should_panic() {
+        let value = 1.5;
+        value.should().eq(2.5);
     }
 
     #[test]
     fn eq_char_success_should_not_panic() {
         let value = 'a';
-        value.should().eq(&'a');
+        value.should().eq('a');
     }
 
     #[test]
     #[should_panic(expected = "Expected 'a' to equal to 'b'")]
     fn eq_char_fail_should_panic() {
         let value = 'a';
-        value.should().eq(&'b');
+        value.should().eq('b');
     }
 
     #[test]
     fn eq_bool_success_should_not_panic() {
         let value = true;
-        value.should().eq(&true);
+        value.should().eq(true);
     }
 
     #[test]
     #[should_panic(expected = "Expected true to equal to false")]
     fn eq_bool_fail_should_panic() {
         let value = true;
-        value.should().eq(&false);
+        value.should().eq(false);
     }
 
     #[test]
@@ -233,14 +393,14 @@ mod tests {
     #[test]
     fn eq_option_success_should_not_panic() {
         let value = Some(42);
-        value.should().eq(&Some(42));
+        value.should().eq(Some(42));
     }
 
     #[test]
     #[should_panic(expected = "Expected Some(42) to equal to None")]
     fn eq_option_fail_should_panic() {
         let value = Some(42);
-        value.should().eq(&None);
+        value.should().eq(None);
     }
 
     #[test]
@@ -255,4 +415,28 @@ mod tests {
         let value: Result<i32, &str> = Ok(42);
         value.should().eq(&Err("error"));
     }
+
+    #[test]
+    #[should_panic(
+        expected = "Diff (- expected, + actual):\n  Session {\n-     user: \"bob\",\n+     user: \"ada\",\n      token: [token],\n  }"
+    )]
+    fn eq_struct_fail_should_print_redacted_diff() {
+        #[derive(PartialEq, Debug)]
+        struct Session {
+            user: &'static str,
+            token: u64,
+        }
+
+        Redactions::new().field(".token", "[token]").bind(|| {
+            Session {
+                user: "ada",
+                token: 1,
+            }
+            .should()
+            .eq(&Session {
+                user: "bob",
+                token: 2,
+            });
+        });
+    }
 }
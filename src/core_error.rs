@@ -0,0 +1,483 @@
+use crate::Should;
+use std::any::type_name;
+use std::error::Error;
+use std::iter;
+
+/// Iterates over the sources of `error`, starting with its direct cause.
+fn sources<'e, E>(error: &'e E) -> impl Iterator<Item = &'e (dyn Error + 'static)>
+where
+    E: Error + ?Sized,
+{
+    iter::successors(error.source(), |&source| source.source())
+}
+
+/// Formats the error and its sources, one cause per line.
+pub(crate) fn describe_chain<E>(error: &E) -> String
+where
+    E: Error + ?Sized,
+{
+    iter::once(format!("  {}", error))
+        .chain(sources(error).map(|source| format!("  caused by: {}", source)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl<'a, E> Should<'a, E>
+where
+    E: Error + ?Sized,
+{
+    /// Asserts that the error's own message contains `expected`.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// let error = "abc".parse::<i32>().unwrap_err();
+    /// error.should().have_message_containing("invalid digit");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the message does not contain `expected`, printing the full
+    /// error chain.
+    #[track_caller]
+    pub fn have_message_containing(&self, expected: &str) {
+        self.check(self.actual().to_string().contains(expected), || {
+            format!(
+                "Expected error message to contain {:?}, but the error chain was:\n{}",
+                expected,
+                describe_chain(self.actual())
+            )
+        });
+    }
+
+    /// Asserts that the error, or any error in its `source()` chain, has a
+    /// message containing `expected`.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    /// use std::error::Error;
+    /// use std::fmt;
+    ///
+    /// #[derive(Debug)]
+    /// struct ConfigError(std::num::ParseIntError);
+    ///
+    /// impl fmt::Display for ConfigError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "failed to load config")
+    ///     }
+    /// }
+    ///
+    /// impl Error for ConfigError {
+    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    ///         Some(&self.0)
+    ///     }
+    /// }
+    ///
+    /// let error = ConfigError("x".parse::<i32>().unwrap_err());
+    /// error.should().have_chain_containing("invalid digit");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if no message in the chain contains `expected`, printing the
+    /// full error chain.
+    #[track_caller]
+    pub fn have_chain_containing(&self, expected: &str) {
+        let found = self.actual().to_string().contains(expected)
+            || sources(self.actual()).any(|source| source.to_string().contains(expected));
+
+        self.check(found, || {
+            format!(
+                "Expected error chain to contain {:?}, but the error chain was:\n{}",
+                expected,
+                describe_chain(self.actual())
+            )
+        });
+    }
+
+    /// Asserts that an error in the `source()` chain has type `T`, and returns
+    /// a [`Should`] for the first such source.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    /// use std::error::Error;
+    /// use std::fmt;
+    /// use std::num::ParseIntError;
+    ///
+    /// #[derive(Debug)]
+    /// struct ConfigError(ParseIntError);
+    ///
+    /// impl fmt::Display for ConfigError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "failed to load config")
+    ///     }
+    /// }
+    ///
+    /// impl Error for ConfigError {
+    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    ///         Some(&self.0)
+    ///     }
+    /// }
+    ///
+    /// let error = ConfigError("x".parse::<i32>().unwrap_err());
+    /// error
+    ///     .should()
+    ///     .have_source_of_type::<ParseIntError>()
+    ///     .have_message_containing("invalid digit");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if no source has type `T`, printing the full error chain.
+    #[track_caller]
+    pub fn have_source_of_type<T>(&self) -> Should<'_, T>
+    where
+        T: Error + 'static,
+    {
+        match sources(self.actual()).find_map(|source| source.downcast_ref::<T>()) {
+            Some(source) => Should::new(source, self.path().map(String::from)),
+            None => self.fail(format!(
+                "Expected error to have a source of type {}, but the error chain was:\n{}",
+                type_name::<T>(),
+                describe_chain(self.actual())
+            )),
+        }
+    }
+}
+
+macro_rules! impl_be_error_of_type {
+    ($($ty:ty => |$actual:ident| $as_error:expr),* $(,)?) => {
+        $(
+            impl<'a> Should<'a, $ty> {
+                /// Asserts that the error has concrete type `T`, and returns a
+                /// [`Should`] for the downcast error.
+                ///
+                /// # Examples
+                /// ```
+                /// use shoulds::Shouldable;
+                /// use std::error::Error;
+                /// use std::num::ParseIntError;
+                ///
+                /// let error: Box<dyn Error + Send + Sync> = "x".parse::<i32>().unwrap_err().into();
+                /// error.should().be_error_of_type::<ParseIntError>();
+                /// ```
+                ///
+                /// # Panics
+                /// Panics if the error has another type, printing the full error chain.
+                #[track_caller]
+                pub fn be_error_of_type<T>(&self) -> Should<'_, T>
+                where
+                    T: Error + 'static,
+                {
+                    let $actual = self.actual();
+                    let error: &(dyn Error + 'static) = $as_error;
+
+                    match error.downcast_ref::<T>() {
+                        Some(error) => Should::new(error, self.path().map(String::from)),
+                        None => self.fail(format!(
+                            "Expected error to be of type {}, but the error chain was:\n{}",
+                            type_name::<T>(),
+                            describe_chain(error)
+                        )),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_be_error_of_type!(
+    dyn Error + 'static => |actual| actual,
+    dyn Error + Send + Sync + 'static => |actual| actual,
+    Box<dyn Error + 'static> => |actual| &**actual,
+    Box<dyn Error + Send + Sync + 'static> => |actual| &**actual,
+);
+
+/// Implements the assertions for an error report type such as
+/// `anyhow::Error`, whose contexts are added by the method `$wrap` of the
+/// extension trait `$ext`.
+#[cfg(any(feature = "anyhow", feature = "eyre"))]
+macro_rules! impl_report_assertions {
+    ($krate:ident :: $report:ident, $ext:ident, $wrap:ident) => {
+        #[doc = concat!("Assertions for [`", stringify!($krate), "::", stringify!($report), "`].")]
+        ///
+        #[doc = concat!("`", stringify!($krate), "::", stringify!($report), "` dereferences to `dyn Error`, so [`Should::as_deref`](crate::Should::as_deref) also")]
+        /// makes the `std::error::Error` assertions, such as
+        /// [`have_chain_containing`](crate::Should::have_chain_containing), available.
+        impl<'a> $crate::Should<'a, $krate::$report> {
+            /// Asserts that the error, or a context attached to it, has type `T`, and
+            /// returns a [`Should`](crate::Should) for it.
+            ///
+            /// # Examples
+            /// ```
+            #[doc = concat!("use ", stringify!($krate), "::", stringify!($ext), ";")]
+            /// use shoulds::Shouldable;
+            /// use std::num::ParseIntError;
+            ///
+            #[doc = concat!("let error = \"x\".parse::<u16>().", stringify!($wrap), "(\"reading port\").unwrap_err();")]
+            /// error.should().be_error_of_type::<ParseIntError>();
+            /// ```
+            ///
+            /// # Panics
+            /// Panics if neither the error nor its contexts have type `T`, printing
+            /// the full error chain.
+            #[track_caller]
+            pub fn be_error_of_type<T>(&self) -> $crate::Should<'_, T>
+            where
+                T: std::fmt::Display + std::fmt::Debug + Send + Sync + 'static,
+            {
+                match self.actual().downcast_ref::<T>() {
+                    Some(error) => $crate::Should::new(error, self.path().map(String::from)),
+                    None => self.fail(format!(
+                        "Expected error to be of type {}, but the error chain was:\n{}",
+                        std::any::type_name::<T>(),
+                        $crate::core_error::describe_chain(&**self.actual())
+                    )),
+                }
+            }
+
+            /// Asserts that one of the layers in the error chain has exactly the
+            #[doc = concat!("message `expected`, as added by `.", stringify!($wrap), "(..)`.")]
+            ///
+            /// # Examples
+            /// ```
+            #[doc = concat!("use ", stringify!($krate), "::", stringify!($ext), ";")]
+            /// use shoulds::Shouldable;
+            ///
+            /// let error = "x"
+            ///     .parse::<u16>()
+            #[doc = concat!("    .", stringify!($wrap), "(\"reading port\")")]
+            #[doc = concat!("    .", stringify!($wrap), "(\"loading config\")")]
+            ///     .unwrap_err();
+            /// error.should().have_context("reading port");
+            /// ```
+            ///
+            /// # Panics
+            /// Panics if no layer has that message, printing the full error chain.
+            #[track_caller]
+            pub fn have_context(&self, expected: &str) {
+                let found = self
+                    .actual()
+                    .chain()
+                    .any(|layer| layer.to_string() == expected);
+
+                self.check(found, || {
+                    format!(
+                        "Expected error to have context {:?}, but the error chain was:\n{}",
+                        expected,
+                        $crate::core_error::describe_chain(&**self.actual())
+                    )
+                });
+            }
+        }
+    };
+}
+
+#[cfg(any(feature = "anyhow", feature = "eyre"))]
+pub(crate) use impl_report_assertions;
+
+/// Generates the tests of the assertions implemented by
+/// [`impl_report_assertions`], with `$new` as the macro creating a report from
+/// a message.
+#[cfg(all(test, any(feature = "anyhow", feature = "eyre")))]
+macro_rules! report_assertion_tests {
+    ($krate:ident :: $report:ident, $ext:ident, $wrap:ident, $new:ident) => {
+        mod tests {
+            use crate::Shouldable;
+            use std::num::ParseIntError;
+            use $krate::{$ext, $new};
+
+            fn load_config() -> Result<u16, $krate::$report> {
+                let port = "80a".parse::<u16>().$wrap("reading port")?;
+                Ok(port)
+            }
+
+            fn error() -> $krate::$report {
+                load_config().$wrap("loading config").unwrap_err()
+            }
+
+            #[test]
+            fn be_error_of_type_success_should_not_panic() {
+                error()
+                    .should()
+                    .be_error_of_type::<ParseIntError>()
+                    .have_message_containing("invalid digit");
+            }
+
+            #[test]
+            #[should_panic(
+                expected = "Error, but the error chain was:\n  loading config\n  caused by: reading port\n  caused by: invalid digit found in string"
+            )]
+            fn be_error_of_type_fail_should_panic() {
+                error().should().be_error_of_type::<std::io::Error>();
+            }
+
+            #[test]
+            fn have_context_success_should_not_panic() {
+                error().should().have_context("reading port");
+            }
+
+            #[test]
+            #[should_panic(
+                expected = "Expected error to have context \"reading\", but the error chain was:\n  loading config"
+            )]
+            fn have_context_partial_message_should_panic() {
+                error().should().have_context("reading");
+            }
+
+            #[test]
+            fn as_deref_should_allow_chain_assertions() {
+                error()
+                    .should()
+                    .as_deref()
+                    .have_chain_containing("invalid digit");
+            }
+
+            #[test]
+            #[should_panic(
+                expected = "Expected Result to be Ok, but got Err:\nloading config\n\nCaused by:"
+            )]
+            fn be_ok_should_render_report() {
+                let result: Result<u16, $krate::$report> = Err(error());
+                result.should().be_ok();
+            }
+
+            #[test]
+            #[should_panic(expected = "boom")]
+            fn be_ok_should_include_message() {
+                let result: Result<u16, $krate::$report> = Err($new!("boom"));
+                result.should().be_ok();
+            }
+        }
+    };
+}
+
+#[cfg(all(test, any(feature = "anyhow", feature = "eyre")))]
+pub(crate) use report_assertion_tests;
+
+#[cfg(test)]
+mod tests {
+    use crate::Shouldable;
+    use std::error::Error;
+    use std::fmt;
+    use std::num::ParseIntError;
+
+    #[derive(Debug)]
+    struct ConfigError {
+        source: ParseIntError,
+    }
+
+    impl fmt::Display for ConfigError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "invalid port")
+        }
+    }
+
+    impl Error for ConfigError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.source)
+        }
+    }
+
+    #[derive(Debug)]
+    struct AppError {
+        source: ConfigError,
+    }
+
+    impl fmt::Display for AppError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "loading config")
+        }
+    }
+
+    impl Error for AppError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.source)
+        }
+    }
+
+    fn app_error() -> AppError {
+        AppError {
+            source: ConfigError {
+                source: "80a".parse::<u16>().unwrap_err(),
+            },
+        }
+    }
+
+    #[test]
+    fn have_message_containing_success_should_not_panic() {
+        app_error().should().have_message_containing("config");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected error message to contain \"port\", but the error chain was:\n  loading config\n  caused by: invalid port\n  caused by: invalid digit found in string"
+    )]
+    fn have_message_containing_fail_should_panic() {
+        app_error().should().have_message_containing("port");
+    }
+
+    #[test]
+    fn have_chain_containing_success_should_not_panic() {
+        app_error().should().have_chain_containing("invalid digit");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected error chain to contain \"timeout\", but the error chain was:\n  loading config"
+    )]
+    fn have_chain_containing_fail_should_panic() {
+        app_error().should().have_chain_containing("timeout");
+    }
+
+    #[test]
+    fn have_source_of_type_success_should_not_panic() {
+        app_error()
+            .should()
+            .have_source_of_type::<ConfigError>()
+            .have_message_containing("port");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected error to have a source of type core::fmt::Error")]
+    fn have_source_of_type_fail_should_panic() {
+        app_error().should().have_source_of_type::<fmt::Error>();
+    }
+
+    #[test]
+    fn be_error_of_type_success_should_not_panic() {
+        let error: Box<dyn Error> = Box::new(app_error());
+        error
+            .should()
+            .be_error_of_type::<AppError>()
+            .have_source_of_type::<ParseIntError>();
+    }
+
+    #[test]
+    fn be_error_of_type_dyn_success_should_not_panic() {
+        let error: Box<dyn Error + Send + Sync> = Box::new(app_error());
+        error.should().as_deref().be_error_of_type::<AppError>();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "ParseIntError, but the error chain was:\n  loading config\n  caused by: invalid port"
+    )]
+    fn be_error_of_type_fail_should_panic() {
+        let error: Box<dyn Error> = Box::new(app_error());
+        error.should().be_error_of_type::<ParseIntError>();
+    }
+
+    #[test]
+    fn boxed_error_via_as_deref_should_not_panic() {
+        fn load() -> Result<(), Box<dyn Error>> {
+            Err(Box::new(app_error()))
+        }
+
+        load()
+            .unwrap_err()
+            .should()
+            .as_deref()
+            .have_chain_containing("invalid port");
+    }
+}
@@ -0,0 +1,6 @@
+use crate::core_error::impl_report_assertions;
+
+impl_report_assertions!(eyre::Report, WrapErr, wrap_err);
+
+#[cfg(test)]
+crate::core_error::report_assertion_tests!(eyre::Report, WrapErr, wrap_err, eyre);
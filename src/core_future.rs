@@ -0,0 +1,369 @@
+use crate::{ShouldMut, ShouldOwned, Shouldable};
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+/// Upper bound on polls for futures that keep waking themselves.
+const MAX_POLLS: usize = 10_000;
+
+/// A waker that only counts how often it was woken.
+#[derive(Default)]
+struct CountingWaker {
+    wakes: AtomicUsize,
+}
+
+impl CountingWaker {
+    fn wakes(&self) -> usize {
+        self.wakes.load(Ordering::SeqCst)
+    }
+}
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.wakes.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+impl<'a, Fut> ShouldMut<'a, Fut>
+where
+    Fut: Future + Unpin + ?Sized,
+    Fut::Output: Debug,
+{
+    /// Polls the future once with `waker`.
+    #[track_caller]
+    fn poll_with(&mut self, waker: &Waker) -> Poll<Fut::Output> {
+        let future = self.actual_mut();
+        Pin::new(future).poll(&mut Context::from_waker(waker))
+    }
+
+    /// Asserts that polling the future once returns `Pending`.
+    ///
+    /// The future is polled with a waker that only counts wake-ups, so no
+    /// runtime is needed. Futures that are not `Unpin` can be pinned with `Box::pin`.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// std::future::pending::<u32>().into_should().be_pending();
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the future is ready, printing the value it produced.
+    #[track_caller]
+    pub fn be_pending(&mut self) -> &mut Self {
+        let waker = Waker::from(Arc::new(CountingWaker::default()));
+
+        if let Poll::Ready(value) = self.poll_with(&waker) {
+            self.fail(format!(
+                "Expected future to be pending, but it was ready with {:?} after 1 poll",
+                value
+            ));
+        }
+
+        self
+    }
+
+    /// Asserts that polling the future once returns `Ready`, and returns a
+    /// [`Should`] for the value it produced.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// std::future::ready(42).into_should().be_ready().eq(&42);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the future is pending.
+    #[track_caller]
+    pub fn be_ready(&mut self) -> ShouldOwned<'a, Fut::Output>
+    where
+        Fut::Output: 'a,
+    {
+        let waker = Waker::from(Arc::new(CountingWaker::default()));
+
+        match self.poll_with(&waker) {
+            Poll::Ready(value) => {
+                let should = value.into_should();
+
+                match self.path() {
+                    Some(path) => should.named(path),
+                    None => should,
+                }
+            }
+            Poll::Pending => {
+                self.fail("Expected future to be ready, but it was pending after 1 poll")
+            }
+        }
+    }
+
+    /// Asserts that the future resolves to `expected`.
+    ///
+    /// The future is polled again for as long as it wakes its waker while
+    /// pending, as a single-threaded executor would, so futures that yield
+    /// to the executor still resolve.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// Box::pin(async { 6 * 7 }).into_should().resolve_to(&42);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the future resolves to another value, or stays pending
+    /// without waking its waker, printing the value and poll count.
+    #[track_caller]
+    pub fn resolve_to<T>(&mut self, expected: &T) -> &mut Self
+    where
+        Fut::Output: PartialEq<T>,
+        T: Debug + ?Sized,
+    {
+        let counter = Arc::new(CountingWaker::default());
+        let waker = Waker::from(Arc::clone(&counter));
+
+        for polls in 1..=MAX_POLLS {
+            let wakes = counter.wakes();
+
+            match self.poll_with(&waker) {
+                Poll::Ready(value) => {
+                    self.check(value == *expected, || {
+                        format!(
+                            "Expected future to resolve to {:?}, but it resolved to {:?} after {} poll(s)",
+                            expected, value, polls
+                        )
+                    });
+
+                    return self;
+                }
+                Poll::Pending if counter.wakes() == wakes => self.fail(format!(
+                    "Expected future to resolve to {:?}, but it was pending after {} poll(s) without waking",
+                    expected, polls
+                )),
+                Poll::Pending => {}
+            }
+        }
+
+        self.fail(format!(
+            "Expected future to resolve to {:?}, but it was still pending after {} poll(s)",
+            expected, MAX_POLLS
+        ))
+    }
+
+    /// Asserts that the future wakes its waker for the first time during poll
+    /// number `expected`, re-polling it while it stays pending.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    /// use std::future::Future;
+    /// use std::pin::Pin;
+    /// use std::task::{Context, Poll};
+    ///
+    /// struct Countdown(u32);
+    ///
+    /// impl Future for Countdown {
+    ///     type Output = ();
+    ///
+    ///     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+    ///         if self.0 == 0 {
+    ///             return Poll::Ready(());
+    ///         }
+    ///         self.0 -= 1;
+    ///         if self.0 == 0 {
+    ///             cx.waker().wake_by_ref();
+    ///         }
+    ///         Poll::Pending
+    ///     }
+    /// }
+    ///
+    /// Countdown(3).into_should().wake_after_n_polls(3).be_ready();
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the future wakes earlier, is ready first, or has not woken
+    /// after `expected` polls.
+    #[track_caller]
+    pub fn wake_after_n_polls(&mut self, expected: usize) -> &mut Self {
+        let counter = Arc::new(CountingWaker::default());
+        let waker = Waker::from(Arc::clone(&counter));
+
+        for polls in 1..=expected {
+            if let Poll::Ready(value) = self.poll_with(&waker) {
+                self.fail(format!(
+                    "Expected future to wake after {} poll(s), but it was ready with {:?} after {} poll(s)",
+                    expected, value, polls
+                ));
+            }
+
+            if counter.wakes() > 0 {
+                self.check(polls == expected, || {
+                    format!(
+                        "Expected future to wake after {} poll(s), but it woke after {} poll(s)",
+                        expected, polls
+                    )
+                });
+
+                return self;
+            }
+        }
+
+        self.fail(format!(
+            "Expected future to wake after {} poll(s), but it had not woken after {} poll(s)",
+            expected, expected
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Shouldable;
+    use std::future::{self, Future};
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    /// Yields to the executor `yields` times before resolving to `value`.
+    struct YieldThen {
+        yields: usize,
+        value: u32,
+    }
+
+    impl Future for YieldThen {
+        type Output = u32;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+            if self.yields == 0 {
+                return Poll::Ready(self.value);
+            }
+
+            self.yields -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn be_pending_success_should_not_panic() {
+        future::pending::<u32>().into_should().be_pending();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected future to be pending, but it was ready with 42 after 1 poll"
+    )]
+    fn be_pending_fail_should_panic() {
+        future::ready(42).into_should().be_pending();
+    }
+
+    #[test]
+    fn be_ready_success_should_return_value() {
+        future::ready("done").into_should().be_ready().eq(&"done");
+    }
+
+    #[test]
+    #[should_panic(expected = "task: Expected future to be ready, but it was pending after 1 poll")]
+    fn be_ready_fail_should_panic_with_path() {
+        YieldThen {
+            yields: 1,
+            value: 1,
+        }
+        .into_should()
+        .named("task")
+        .be_ready();
+    }
+
+    #[test]
+    fn be_pending_then_ready_should_keep_state() {
+        let mut task = YieldThen {
+            yields: 1,
+            value: 7,
+        };
+        task.should_mut().be_pending().be_ready().eq(&7);
+    }
+
+    #[test]
+    fn resolve_to_success_should_not_panic() {
+        YieldThen {
+            yields: 3,
+            value: 5,
+        }
+        .into_should()
+        .resolve_to(&5);
+    }
+
+    #[test]
+    fn resolve_to_async_block_should_not_panic() {
+        Box::pin(async { "a".repeat(3) })
+            .into_should()
+            .resolve_to("aaa");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected future to resolve to 6, but it resolved to 5 after 3 poll(s)"
+    )]
+    fn resolve_to_wrong_value_should_panic() {
+        YieldThen {
+            yields: 2,
+            value: 5,
+        }
+        .into_should()
+        .resolve_to(&6);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected future to resolve to 1, but it was pending after 1 poll(s) without waking"
+    )]
+    fn resolve_to_stalled_should_panic() {
+        future::pending::<u32>().into_should().resolve_to(&1);
+    }
+
+    #[test]
+    fn wake_after_n_polls_success_should_not_panic() {
+        YieldThen {
+            yields: 2,
+            value: 0,
+        }
+        .into_should()
+        .wake_after_n_polls(1)
+        .resolve_to(&0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected future to wake after 3 poll(s), but it woke after 1 poll(s)"
+    )]
+    fn wake_after_n_polls_early_should_panic() {
+        YieldThen {
+            yields: 2,
+            value: 0,
+        }
+        .into_should()
+        .wake_after_n_polls(3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected future to wake after 2 poll(s), but it was ready with 9 after 1 poll(s)"
+    )]
+    fn wake_after_n_polls_ready_should_panic() {
+        future::ready(9).into_should().wake_after_n_polls(2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected future to wake after 2 poll(s), but it had not woken after 2 poll(s)"
+    )]
+    fn wake_after_n_polls_never_should_panic() {
+        future::pending::<u32>().into_should().wake_after_n_polls(2);
+    }
+}
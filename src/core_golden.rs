@@ -0,0 +1,459 @@
+use crate::core_snapshot::{update_mode, UPDATE_VAR};
+use crate::core_text_diff::{line_diff, CONTEXT, MAX_DIFF_CELLS};
+use crate::Should;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Number of bytes per row of a hex dump.
+const HEX_ROW: usize = 16;
+
+/// How line endings are compared by [`Should::match_golden_file_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Newlines {
+    /// Compares the text byte for byte.
+    Exact,
+    /// Treats `\r\n` and `\n` line endings as equal.
+    #[default]
+    Normalize,
+    /// Treats `\r\n` and `\n` as equal and ignores trailing newlines.
+    IgnoreTrailing,
+}
+
+impl Newlines {
+    fn apply(self, text: &str) -> String {
+        match self {
+            Newlines::Exact => text.to_string(),
+            Newlines::Normalize => text.replace("\r\n", "\n"),
+            Newlines::IgnoreTrailing => text
+                .replace("\r\n", "\n")
+                .trim_end_matches('\n')
+                .to_string(),
+        }
+    }
+}
+
+/// Resolves a golden file path relative to the package root.
+fn resolve_golden(path: &Path) -> PathBuf {
+    env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .or_else(|| env::current_dir().ok())
+        .unwrap_or_default()
+        .join(path)
+}
+
+/// Writes `contents` to `path`, creating its directory if needed.
+fn write_golden(path: &Path, contents: &[u8]) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    fs::write(path, contents)
+}
+
+/// Formats `bytes` as a hex dump with offsets, starting at `start`, and a
+/// printable ASCII column.
+fn hex_dump(bytes: &[u8], start: usize) -> String {
+    bytes
+        .chunks(HEX_ROW)
+        .enumerate()
+        .map(|(row, chunk)| {
+            let hex: Vec<String> = chunk.iter().map(|b| format!("{:02x}", b)).collect();
+            let ascii: String = chunk
+                .iter()
+                .map(|&b| match b {
+                    0x20..=0x7e => b as char,
+                    _ => '.',
+                })
+                .collect();
+
+            format!(
+                "{:08x}  {:<width$}  |{}|",
+                start + row * HEX_ROW,
+                hex.join(" "),
+                ascii,
+                width = HEX_ROW * 3 - 1
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders a diff of the hex dumps of `expected` and `actual`, which first
+/// differ at `offset`.
+///
+/// When diffing the full dumps would be too expensive, such as after an
+/// insertion near the start of a large file shifts every row, only the rows
+/// around `offset` are shown.
+fn hex_diff(expected: &[u8], actual: &[u8], offset: usize) -> String {
+    let rows = |bytes: &[u8]| (bytes.len() + HEX_ROW - 1) / HEX_ROW;
+
+    if rows(expected).saturating_mul(rows(actual)) <= MAX_DIFF_CELLS {
+        return line_diff(&hex_dump(expected, 0), &hex_dump(actual, 0));
+    }
+
+    let start = (offset / HEX_ROW).saturating_sub(CONTEXT) * HEX_ROW;
+    let end = (offset / HEX_ROW + CONTEXT + 1) * HEX_ROW;
+    let window =
+        |bytes: &'_ [u8]| hex_dump(&bytes[start.min(bytes.len())..end.min(bytes.len())], start);
+
+    format!(
+        "  (showing the rows around the first difference)\n{}",
+        line_diff(&window(expected), &window(actual))
+    )
+}
+
+impl<'a, T: ?Sized> Should<'a, T> {
+    /// Reads the golden file at `path`, or returns `None` if it is missing.
+    #[track_caller]
+    fn read_golden(&self, path: &Path) -> Option<Vec<u8>> {
+        match fs::read(path) {
+            Ok(expected) => Some(expected),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+            Err(error) => self.fail(format!(
+                "Could not read golden file {}: {}",
+                path.display(),
+                error
+            )),
+        }
+    }
+
+    /// Replaces the golden file at `path` with `actual`.
+    #[track_caller]
+    fn update_golden(&self, path: &Path, actual: &[u8]) {
+        if let Err(error) = write_golden(path, actual) {
+            self.fail(format!(
+                "Could not update golden file {}: {}",
+                path.display(),
+                error
+            ));
+        }
+    }
+
+    #[track_caller]
+    fn missing_golden(&self, path: &Path) -> ! {
+        self.fail(format!(
+            "Expected output to match golden file {}, but it does not exist; rerun with {}=1 to create it",
+            path.display(),
+            UPDATE_VAR
+        ))
+    }
+}
+
+impl<'a> Should<'a, str> {
+    /// Asserts that the text equals the contents of the golden file at
+    /// `path`, relative to the package root, treating `\r\n` and `\n` line
+    /// endings as equal.
+    ///
+    /// Run the tests with `SHOULDS_UPDATE=1` to write the actual text to the
+    /// golden file instead.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use shoulds::Shouldable;
+    ///
+    /// let generated = "pub struct User;\n";
+    /// generated.should().match_golden_file("tests/golden/user.rs");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics with a line diff if the text differs, or if the golden file is
+    /// missing, unless update mode is on.
+    #[track_caller]
+    pub fn match_golden_file(&self, path: impl AsRef<Path>) {
+        self.match_golden_file_with(path, Newlines::default());
+    }
+
+    /// Asserts that the text equals the contents of the golden file at
+    /// `path`, comparing line endings as `newlines` specifies.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use shoulds::{Newlines, Shouldable};
+    ///
+    /// let report = "ok\r\n";
+    /// report
+    ///     .should()
+    ///     .match_golden_file_with("tests/golden/report.txt", Newlines::Exact);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics with a line diff if the text differs, or if the golden file is
+    /// missing, unless update mode is on.
+    #[track_caller]
+    pub fn match_golden_file_with(&self, path: impl AsRef<Path>, newlines: Newlines) {
+        let path = resolve_golden(path.as_ref());
+        self.check_golden_text(&path, newlines, update_mode());
+    }
+
+    #[track_caller]
+    fn check_golden_text(&self, path: &Path, newlines: Newlines, update: bool) {
+        let actual = newlines.apply(self.actual());
+        let expected = self
+            .read_golden(path)
+            .map(|expected| newlines.apply(&String::from_utf8_lossy(&expected)));
+
+        match expected {
+            Some(expected) if expected == actual => {}
+            _ if update => self.update_golden(path, self.actual().as_bytes()),
+            None => self.missing_golden(path),
+            Some(expected) => {
+                let diff = line_diff(&expected, &actual);
+                let diff = match diff.lines().any(|line| line.starts_with(['-', '+'])) {
+                    true => diff,
+                    false => "  (the texts differ only in line endings)".to_string(),
+                };
+
+                self.fail(format!(
+                    "Expected text to match golden file {}, but it differed (- golden, + actual):\n{}\nRerun with {}=1 to update it",
+                    path.display(),
+                    diff,
+                    UPDATE_VAR
+                ));
+            }
+        }
+    }
+}
+
+impl<'a> Should<'a, [u8]> {
+    /// Asserts that the bytes equal the contents of the golden file at `path`,
+    /// relative to the package root.
+    ///
+    /// Run the tests with `SHOULDS_UPDATE=1` to write the actual bytes to the
+    /// golden file instead.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use shoulds::Shouldable;
+    ///
+    /// let image: &[u8] = &[0x89, b'P', b'N', b'G'];
+    /// image.should().match_golden_file("tests/golden/logo.png");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics with a hex diff if the bytes differ, or if the golden file is
+    /// missing, unless update mode is on.
+    #[track_caller]
+    pub fn match_golden_file(&self, path: impl AsRef<Path>) {
+        let path = resolve_golden(path.as_ref());
+        self.check_golden_bytes(&path, update_mode());
+    }
+
+    #[track_caller]
+    fn check_golden_bytes(&self, path: &Path, update: bool) {
+        let actual = self.actual();
+
+        match self.read_golden(path) {
+            Some(expected) if expected == actual => {}
+            _ if update => self.update_golden(path, actual),
+            None => self.missing_golden(path),
+            Some(expected) => {
+                let offset = expected
+                    .iter()
+                    .zip(actual)
+                    .position(|(e, a)| e != a)
+                    .unwrap_or(expected.len().min(actual.len()));
+
+                self.fail(format!(
+                    "Expected bytes to match golden file {}, but they first differed at offset {:#x} ({} golden bytes, {} actual bytes) (- golden, + actual):\n{}\nRerun with {}=1 to update it",
+                    path.display(),
+                    offset,
+                    expected.len(),
+                    actual.len(),
+                    hex_diff(&expected, actual, offset),
+                    UPDATE_VAR
+                ));
+            }
+        }
+    }
+}
+
+impl<'a> Should<'a, String> {
+    /// Asserts that the string equals the contents of the golden file at
+    /// `path`, as [`Should::<str>::match_golden_file`] does.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use shoulds::Shouldable;
+    ///
+    /// let output = format!("pub struct {};\n", "User");
+    /// output.should().match_golden_file("tests/golden/user.rs");
+    /// ```
+    #[track_caller]
+    pub fn match_golden_file(&self, path: impl AsRef<Path>) {
+        self.as_deref().match_golden_file(path);
+    }
+
+    /// Asserts that the string equals the contents of the golden file at
+    /// `path`, comparing line endings as `newlines` specifies.
+    #[track_caller]
+    pub fn match_golden_file_with(&self, path: impl AsRef<Path>, newlines: Newlines) {
+        self.as_deref().match_golden_file_with(path, newlines);
+    }
+}
+
+impl<'a> Should<'a, Vec<u8>> {
+    /// Asserts that the bytes equal the contents of the golden file at `path`,
+    /// as [`Should::<[u8]>::match_golden_file`] does.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use shoulds::Shouldable;
+    ///
+    /// let image = vec![0x89, b'P', b'N', b'G'];
+    /// image.should().match_golden_file("tests/golden/logo.png");
+    /// ```
+    #[track_caller]
+    pub fn match_golden_file(&self, path: impl AsRef<Path>) {
+        self.as_deref().match_golden_file(path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hex_diff, hex_dump, Newlines};
+    use crate::test_support::TempDir;
+    use crate::Shouldable;
+    use std::fs;
+    use std::path::PathBuf;
+
+    /// Returns a path in a fresh temporary directory, optionally writing
+    /// `contents` to it. The directory is removed when the guard is dropped.
+    fn golden(contents: Option<&[u8]>) -> (TempDir, PathBuf) {
+        let dir = TempDir::new();
+        let path = dir.join("golden.txt");
+
+        if let Some(contents) = contents {
+            fs::write(&path, contents).unwrap();
+        }
+
+        (dir, path)
+    }
+
+    #[test]
+    fn match_golden_file_success_should_not_panic() {
+        let (_dir, path) = golden(Some(b"fn main() {}\r\n"));
+        "fn main() {}\n".should().match_golden_file(&path);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "differed (- golden, + actual):\n  a\n- b\n+ c\nRerun with SHOULDS_UPDATE=1 to update it"
+    )]
+    fn match_golden_file_mismatch_should_panic_with_line_diff() {
+        let (_dir, path) = golden(Some(b"a\nb\n"));
+        "a\nc\n"
+            .should()
+            .check_golden_text(&path, Newlines::Normalize, false);
+    }
+
+    #[test]
+    #[should_panic(expected = "(the texts differ only in line endings)")]
+    fn match_golden_file_exact_should_compare_line_endings() {
+        let (_dir, path) = golden(Some(b"a\r\n"));
+        "a\n"
+            .should()
+            .match_golden_file_with(&path, Newlines::Exact);
+    }
+
+    #[test]
+    fn match_golden_file_ignore_trailing_should_not_panic() {
+        let (_dir, path) = golden(Some(b"a\n\n"));
+        "a".should()
+            .match_golden_file_with(&path, Newlines::IgnoreTrailing);
+    }
+
+    #[test]
+    #[should_panic(expected = "but it does not exist; rerun with SHOULDS_UPDATE=1 to create it")]
+    fn match_golden_file_missing_should_panic() {
+        let (_dir, path) = golden(None);
+        "a".should()
+            .check_golden_text(&path, Newlines::Normalize, false);
+    }
+
+    #[test]
+    fn match_golden_file_update_should_write_file() {
+        let (_dir, path) = golden(None);
+
+        "generated\r\n"
+            .should()
+            .check_golden_text(&path, Newlines::Normalize, true);
+
+        fs::read(&path).unwrap().should().eq(b"generated\r\n");
+    }
+
+    #[test]
+    fn match_golden_file_string_success_should_not_panic() {
+        let (_dir, path) = golden(Some(b"a\n"));
+        let output = String::from("a\r\n");
+
+        output.should().match_golden_file(&path);
+        output
+            .should()
+            .match_golden_file_with(&path, Newlines::IgnoreTrailing);
+    }
+
+    #[test]
+    #[should_panic(expected = "differed (- golden, + actual):\n- a\n+ b")]
+    fn match_golden_file_string_mismatch_should_panic() {
+        let (_dir, path) = golden(Some(b"a\n"));
+        String::from("b\n").should().match_golden_file(&path);
+    }
+
+    #[test]
+    fn match_golden_file_vec_success_should_not_panic() {
+        let (_dir, path) = golden(Some(&[0, 1, 2]));
+        vec![0u8, 1, 2].should().match_golden_file(&path);
+    }
+
+    #[test]
+    #[should_panic(expected = "but they first differed at offset 0x2")]
+    fn match_golden_file_vec_mismatch_should_panic() {
+        let (_dir, path) = golden(Some(&[0, 1, 2]));
+        vec![0u8, 1, 3].should().match_golden_file(&path);
+    }
+
+    #[test]
+    fn match_golden_file_bytes_success_should_not_panic() {
+        let (_dir, path) = golden(Some(&[0, 1, 2]));
+        vec![0u8, 1, 2].should().as_deref().match_golden_file(&path);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "but they first differed at offset 0x11 (18 golden bytes, 18 actual bytes) (- golden, + actual):\n  00000000  00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  |................|\n- 00000010  10 11                                            |..|\n+ 00000010  10 ff                                            |..|"
+    )]
+    fn match_golden_file_bytes_mismatch_should_panic_with_hex_diff() {
+        let expected: Vec<u8> = (0..18).collect();
+        let mut actual = expected.clone();
+        actual[17] = 0xff;
+
+        let (_dir, path) = golden(Some(&expected));
+        actual.should().as_deref().check_golden_bytes(&path, false);
+    }
+
+    #[test]
+    fn hex_dump_should_show_printable_characters() {
+        hex_dump(b"hi\n", 0)
+            .should()
+            .eq("00000000  68 69 0a                                         |hi.|");
+    }
+
+    #[test]
+    fn hex_diff_should_show_window_around_first_difference_in_large_files() {
+        let expected: Vec<u8> = (0..1 << 20).map(|n| n as u8).collect();
+        let mut actual = expected.clone();
+        actual.insert(0x100, 0xff);
+
+        let diff = hex_diff(&expected, &actual, 0x100);
+
+        diff.lines()
+            .next()
+            .should()
+            .eq(Some("  (showing the rows around the first difference)"));
+        diff.lines().count().should().eq(&12);
+        diff.should().as_deref().contain("- 00000100  00 01 02");
+        diff.should().as_deref().contain("+ 00000100  ff 00 01");
+    }
+}
@@ -0,0 +1,629 @@
+use crate::core_redact::redact;
+use crate::core_snapshot::{resolve_source, update_mode, UPDATE_VAR};
+use crate::core_text_diff::line_diff;
+use crate::Should;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Debug;
+use std::fs::{self, OpenOptions};
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::ops::Range;
+use std::panic::Location;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Name of the assertion whose literal argument gets rewritten.
+const METHOD: &str = "match_inline_snapshot";
+
+/// How long to wait for another test process to finish editing a file.
+const LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Age after which a lock file is assumed to be left by a crashed process.
+/// Edits hold the lock for milliseconds.
+const STALE_LOCK: Duration = Duration::from_secs(5);
+
+/// Serializes edits between the threads of this process; a lock file next to
+/// the journal serializes them between processes.
+static EDITING: Mutex<()> = Mutex::new(());
+
+/// The literal rewrites recorded for one source file during an update run,
+/// keyed by the line and column of the call, along with the file as it was
+/// before the first of them and when that was.
+///
+/// Every test binary and doctest of a run is its own process, so the journal
+/// is kept in a file shared between them rather than in memory.
+#[derive(Debug, PartialEq)]
+struct Journal {
+    started: Duration,
+    original: String,
+    edits: BTreeMap<(u32, u32), String>,
+}
+
+impl Journal {
+    /// Encodes the journal as length-prefixed records: the start time in
+    /// nanoseconds and the original source, then `line column length` and
+    /// the value of each edit.
+    fn encode(&self) -> String {
+        let mut text = format!(
+            "{} {}\n{}",
+            self.started.as_nanos(),
+            self.original.len(),
+            self.original
+        );
+
+        for (&(line, column), value) in &self.edits {
+            text.push_str(&format!("{} {} {}\n{}", line, column, value.len(), value));
+        }
+
+        text
+    }
+
+    fn decode(mut text: &str) -> Option<Self> {
+        fn field<'t>(text: &mut &'t str, end: char) -> Option<&'t str> {
+            let (field, rest) = text.split_once(end)?;
+            *text = rest;
+            Some(field)
+        }
+
+        fn value(text: &mut &str, len: &str) -> Option<String> {
+            let len = len.parse().ok()?;
+            let value = text.get(..len)?.to_string();
+            *text = &text[len..];
+            Some(value)
+        }
+
+        let started: u64 = field(&mut text, ' ')?.parse().ok()?;
+        let len = field(&mut text, '\n')?;
+        let original = value(&mut text, len)?;
+        let mut edits = BTreeMap::new();
+
+        while !text.is_empty() {
+            let line = field(&mut text, ' ')?.parse().ok()?;
+            let column = field(&mut text, ' ')?.parse().ok()?;
+            let len = field(&mut text, '\n')?;
+            edits.insert((line, column), value(&mut text, len)?);
+        }
+
+        Some(Journal {
+            started: Duration::from_nanos(started),
+            original,
+            edits,
+        })
+    }
+}
+
+/// Returns where the journal of `file` is kept.
+fn journal_path(file: &Path) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    file.hash(&mut hasher);
+
+    env::temp_dir()
+        .join("shoulds-inline-snapshots")
+        .join(format!("{:016x}", hasher.finish()))
+}
+
+/// Returns when the running test binary was built, i.e. when the source
+/// locations it reports were last valid.
+fn build_time() -> Option<SystemTime> {
+    env::current_exe()
+        .and_then(fs::metadata)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Returns the time since the Unix epoch, or zero for earlier times.
+fn since_epoch(time: SystemTime) -> Duration {
+    time.duration_since(UNIX_EPOCH).unwrap_or_default()
+}
+
+/// A lock file held while a journal and its source file are edited.
+struct FileLock(PathBuf);
+
+impl FileLock {
+    /// Creates the lock file at `path`, waiting for other processes to
+    /// release it. A lock file older than the test binary `built`, or older
+    /// than [`STALE_LOCK`], was left by a process that crashed and is removed.
+    fn acquire(path: PathBuf, built: SystemTime) -> Result<Self, String> {
+        let deadline = Instant::now() + LOCK_TIMEOUT;
+
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Ok(FileLock(path)),
+                Err(error) if error.kind() == io::ErrorKind::AlreadyExists => {
+                    let stale = fs::metadata(&path)
+                        .and_then(|metadata| metadata.modified())
+                        .is_ok_and(|locked| {
+                            locked < built || locked.elapsed().is_ok_and(|age| age > STALE_LOCK)
+                        });
+
+                    if stale {
+                        let _ = fs::remove_file(&path);
+                        continue;
+                    }
+
+                    if Instant::now() >= deadline {
+                        return Err(format!(
+                            "timed out waiting for {}; remove it if no tests are running",
+                            path.display()
+                        ));
+                    }
+
+                    thread::sleep(Duration::from_millis(10));
+                }
+                Err(error) => return Err(error.to_string()),
+            }
+        }
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+/// Normalizes the contents of an inline snapshot literal, so that it can be
+/// written indented on its own lines: a leading newline, a trailing line of
+/// whitespace and the common indentation are removed. Trailing whitespace
+/// within lines is kept, as it is part of the value.
+fn dedent(literal: &str) -> String {
+    let literal = literal.replace("\r\n", "\n");
+    let literal = literal.strip_prefix('\n').unwrap_or(&literal);
+    let literal = match literal.rfind('\n') {
+        Some(index) if literal[index + 1..].trim().is_empty() => &literal[..index],
+        _ => literal,
+    };
+
+    let indent = literal
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    literal
+        .lines()
+        .map(|line| line.get(indent..).unwrap_or(""))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns the byte offset of the 1-based `line` and character `column`.
+fn offset_of(source: &str, line: u32, column: u32) -> Option<usize> {
+    let line_start = if line <= 1 {
+        0
+    } else {
+        source
+            .match_indices('\n')
+            .nth(line as usize - 2)
+            .map(|(index, _)| index + 1)?
+    };
+
+    source[line_start..]
+        .char_indices()
+        .nth(column.saturating_sub(1) as usize)
+        .map(|(index, _)| line_start + index)
+}
+
+/// Finds the string literal passed to the assertion called at `offset`.
+fn find_literal(source: &str, offset: usize) -> Option<Range<usize>> {
+    let call = offset + source[offset..].find(METHOD)? + METHOD.len();
+    let open = call + source[call..].find(|c: char| !c.is_whitespace())?;
+
+    if !source[open..].starts_with('(') {
+        return None;
+    }
+
+    let start = open + 1 + source[open + 1..].find(|c: char| !c.is_whitespace())?;
+    let rest = &source[start..];
+
+    if let Some(raw) = rest.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let terminator = format!("\"{}", "#".repeat(hashes));
+
+        if !raw[hashes..].starts_with('"') {
+            return None;
+        }
+
+        let body = start + 1 + hashes + 1;
+        let end = body + source[body..].find(&terminator)? + terminator.len();
+        Some(start..end)
+    } else if rest.starts_with('"') {
+        let mut escaped = false;
+
+        for (index, c) in rest.char_indices().skip(1) {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => return Some(start..start + index + 1),
+                _ => {}
+            }
+        }
+
+        None
+    } else {
+        None
+    }
+}
+
+/// Formats `value` as a raw string literal, putting multi-line values on
+/// their own lines indented one level deeper than `indent`.
+fn format_literal(value: &str, indent: &str) -> String {
+    let mut hashes = 1;
+    while value.contains(&format!("\"{}", "#".repeat(hashes))) {
+        hashes += 1;
+    }
+    let hashes = "#".repeat(hashes);
+
+    if !value.contains('\n') {
+        return format!("r{}\"{}\"{}", hashes, value, hashes);
+    }
+
+    let body: Vec<String> = value
+        .lines()
+        .map(|line| match line {
+            "" => String::new(),
+            _ => format!("{}    {}", indent, line),
+        })
+        .collect();
+
+    format!("r{}\"\n{}\n{}\"{}", hashes, body.join("\n"), indent, hashes)
+}
+
+/// Rewrites the literals of all `edits` in `original`.
+fn apply_edits(original: &str, edits: &BTreeMap<(u32, u32), String>) -> Result<String, String> {
+    let mut replacements = Vec::new();
+
+    for (&(line, column), value) in edits {
+        let literal = offset_of(original, line, column)
+            .and_then(|offset| find_literal(original, offset))
+            .ok_or_else(|| format!("no string literal found at line {}", line))?;
+
+        let line_start = original[..literal.start].rfind('\n').map_or(0, |i| i + 1);
+        let indent: String = original[line_start..]
+            .chars()
+            .take_while(|c| *c == ' ' || *c == '\t')
+            .collect();
+
+        replacements.push((literal, format_literal(value, &indent)));
+    }
+
+    replacements.sort_by_key(|(range, _)| range.start);
+
+    let mut updated = original.to_string();
+    for (range, literal) in replacements.into_iter().rev() {
+        updated.replace_range(range, &literal);
+    }
+
+    Ok(updated)
+}
+
+/// Records the new value for the literal at `line` and `column` of `file` in
+/// `journal`, and rewrites the file with every edit recorded for it.
+///
+/// Edits are written right away rather than batched at process exit, which
+/// std offers no hook for. Line and column refer to the file as the test
+/// binary, built at `built`, was compiled from, so edits are applied to the
+/// original contents kept in the journal. This lets the test threads and
+/// processes of one run, each updating some of the snapshots in a file, merge
+/// their edits instead of overwriting each other's.
+///
+/// A journal started before the binary was built belongs to an earlier run,
+/// whose edits the binary was already compiled with, and a journal that no
+/// longer matches the file was outdated by other changes; both are discarded.
+fn record_edit(
+    file: &Path,
+    journal: &Path,
+    built: SystemTime,
+    (line, column): (u32, u32),
+    value: &str,
+) -> Result<(), String> {
+    let _editing = EDITING.lock().unwrap_or_else(|e| e.into_inner());
+
+    if let Some(dir) = journal.parent() {
+        fs::create_dir_all(dir).map_err(|error| error.to_string())?;
+    }
+    let _lock = FileLock::acquire(journal.with_extension("lock"), built)?;
+
+    let current = fs::read_to_string(file).map_err(|error| error.to_string())?;
+    let mut state = fs::read_to_string(journal)
+        .ok()
+        .and_then(|text| Journal::decode(&text))
+        .filter(|state| state.started >= since_epoch(built))
+        .filter(|state| {
+            apply_edits(&state.original, &state.edits).is_ok_and(|text| text == current)
+        })
+        .unwrap_or_else(|| Journal {
+            started: since_epoch(SystemTime::now()),
+            original: current,
+            edits: BTreeMap::new(),
+        });
+
+    state.edits.insert((line, column), value.to_string());
+    let updated = apply_edits(&state.original, &state.edits)?;
+
+    fs::write(journal, state.encode()).map_err(|error| error.to_string())?;
+    fs::write(file, updated).map_err(|error| error.to_string())
+}
+
+impl<'a, T: ?Sized> Should<'a, T> {
+    /// Compares `actual` against the inline snapshot `expected` of the call
+    /// at `line` and `column` of `file`, rewriting the literal in update mode.
+    #[track_caller]
+    pub(crate) fn check_inline_snapshot(
+        &self,
+        file: &Path,
+        (line, column): (u32, u32),
+        expected: &str,
+        actual: &str,
+        update: bool,
+    ) {
+        let expected = dedent(expected);
+        let actual = redact(actual);
+        let actual = actual.as_str();
+
+        if expected == actual {
+            return;
+        }
+
+        if update {
+            // Without a known build time, every journal counts as outdated.
+            let built = build_time().unwrap_or_else(SystemTime::now);
+
+            if let Err(error) =
+                record_edit(file, &journal_path(file), built, (line, column), actual)
+            {
+                self.fail(format!(
+                    "Could not update inline snapshot at {}:{}: {}",
+                    file.display(),
+                    line,
+                    error
+                ));
+            }
+
+            return;
+        }
+
+        self.fail(format!(
+            "Expected value to match inline snapshot, but it differed (- snapshot, + actual):\n{}\nRerun with {}=1 to update the literal",
+            line_diff(&expected, actual),
+            UPDATE_VAR
+        ));
+    }
+}
+
+impl<'a, T> Should<'a, T>
+where
+    T: Debug + ?Sized,
+{
+    /// Asserts that the value's pretty `Debug` output (`{:#?}`) matches the
+    /// inline snapshot `expected`.
+    ///
+    /// The literal may be written indented on its own lines; its leading
+    /// newline and common indentation are ignored. Run the tests with
+    /// `SHOULDS_UPDATE=1` to rewrite the literal in the calling source file
+    /// with the actual value. Start with an empty `r#""#` to record a new
+    /// snapshot.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// #[derive(Debug)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// Point { x: 1, y: 2 }.should().match_inline_snapshot(r#"
+    ///     Point {
+    ///         x: 1,
+    ///         y: 2,
+    ///     }
+    /// "#);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics with a line diff if the value differs and update mode is off.
+    #[track_caller]
+    pub fn match_inline_snapshot(&self, expected: &str) {
+        let caller = Location::caller();
+        let file = resolve_source(caller.file());
+        let actual = format!("{:#?}", self.actual());
+
+        self.check_inline_snapshot(
+            &file,
+            (caller.line(), caller.column()),
+            expected,
+            &actual,
+            update_mode(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        apply_edits, dedent, format_literal, journal_path, record_edit, FileLock, Journal,
+    };
+    use crate::test_support::TempDir;
+    use crate::Shouldable;
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn match_inline_snapshot_success_should_not_panic() {
+        vec![1, 2].should().match_inline_snapshot(
+            r#"
+            [
+                1,
+                2,
+            ]
+            "#,
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected value to match inline snapshot, but it differed (- snapshot, + actual):\n- 41\n+ 42\nRerun with SHOULDS_UPDATE=1 to update the literal"
+    )]
+    fn match_inline_snapshot_mismatch_should_panic_with_diff() {
+        42.should().match_inline_snapshot("41");
+    }
+
+    #[test]
+    fn dedent_should_strip_surrounding_lines_and_indentation() {
+        dedent("\n    a {\n        b\n    }\n    ")
+            .should()
+            .eq("a {\n    b\n}");
+        dedent("").should().eq("");
+    }
+
+    #[test]
+    fn dedent_should_keep_trailing_whitespace_of_values() {
+        let value = "a  \n  b \nc";
+
+        let literal = format_literal(value, "    ");
+        let body = &literal["r#\"".len()..literal.len() - "\"#".len()];
+
+        dedent(body).should().eq(value);
+        dedent("a  ").should().eq("a  ");
+    }
+
+    #[test]
+    fn apply_edits_should_rewrite_each_literal() {
+        let source = "fn t() {\n    a.should().match_inline_snapshot(\"old\");\n    b.should().match_inline_snapshot(r#\"\"#);\n}\n";
+        let mut edits = BTreeMap::new();
+        edits.insert((2, 16), "1".to_string());
+        edits.insert((3, 16), "[\n    \"x\",\n]".to_string());
+
+        apply_edits(source, &edits).unwrap().should().eq(
+            "fn t() {\n    a.should().match_inline_snapshot(r#\"1\"#);\n    b.should().match_inline_snapshot(r#\"\n        [\n            \"x\",\n        ]\n    \"#);\n}\n",
+        );
+    }
+
+    #[test]
+    fn apply_edits_should_fail_without_literal() {
+        let mut edits = BTreeMap::new();
+        edits.insert((1, 1), "1".to_string());
+
+        apply_edits("a.should().match_inline_snapshot(expected);", &edits)
+            .should()
+            .be_err();
+    }
+
+    #[test]
+    fn check_inline_snapshot_update_should_rewrite_source_file() {
+        let dir = TempDir::new();
+        let file = dir.join("source.rs");
+        fs::write(&file, "x.should().match_inline_snapshot(\"\");\n").unwrap();
+
+        "value"
+            .should()
+            .check_inline_snapshot(&file, (1, 12), "", "2", true);
+        let journal = journal_path(&file);
+        let _ = fs::remove_file(&journal);
+        let _ = fs::remove_dir(journal.parent().unwrap());
+
+        fs::read_to_string(&file)
+            .unwrap()
+            .should()
+            .eq("x.should().match_inline_snapshot(r#\"2\"#);\n");
+    }
+
+    #[test]
+    fn record_edit_should_merge_edits_from_separate_processes() {
+        let dir = TempDir::new();
+        let file = dir.join("source.rs");
+        let journal = dir.join("journal");
+        fs::write(
+            &file,
+            "a.should().match_inline_snapshot(\"\");\nb.should().match_inline_snapshot(\"\");\n",
+        )
+        .unwrap();
+
+        // Each call starts from the files alone, as a separate test process
+        // would, and refers to the lines as they were compiled.
+        record_edit(&file, &journal, UNIX_EPOCH, (1, 12), "[\n    1,\n]").unwrap();
+        record_edit(&file, &journal, UNIX_EPOCH, (2, 12), "2").unwrap();
+
+        fs::read_to_string(&file).unwrap().should().eq(
+            "a.should().match_inline_snapshot(r#\"\n    [\n        1,\n    ]\n\"#);\nb.should().match_inline_snapshot(r#\"2\"#);\n",
+        );
+    }
+
+    #[test]
+    fn record_edit_should_discard_journal_of_changed_file() {
+        let dir = TempDir::new();
+        let file = dir.join("source.rs");
+        let journal = dir.join("journal");
+        fs::write(&file, "a.should().match_inline_snapshot(\"\");\n").unwrap();
+        record_edit(&file, &journal, UNIX_EPOCH, (1, 12), "1").unwrap();
+
+        fs::write(&file, "\nb.should().match_inline_snapshot(\"\");\n").unwrap();
+        record_edit(&file, &journal, UNIX_EPOCH, (2, 12), "2").unwrap();
+
+        fs::read_to_string(&file)
+            .unwrap()
+            .should()
+            .eq("\nb.should().match_inline_snapshot(r#\"2\"#);\n");
+    }
+
+    #[test]
+    fn record_edit_should_discard_journal_of_earlier_run() {
+        let dir = TempDir::new();
+        let file = dir.join("source.rs");
+        let journal = dir.join("journal");
+        fs::write(
+            &file,
+            "a.should().match_inline_snapshot(\"\");\nb.should().match_inline_snapshot(\"\");\n",
+        )
+        .unwrap();
+        record_edit(&file, &journal, UNIX_EPOCH, (1, 12), "[\n    1,\n]").unwrap();
+
+        // The next run's binary is built from the rewritten file, where the
+        // second literal has moved to line 6.
+        let rebuilt = SystemTime::now() + Duration::from_secs(1);
+        record_edit(&file, &journal, rebuilt, (6, 12), "2").unwrap();
+
+        fs::read_to_string(&file).unwrap().should().eq(
+            "a.should().match_inline_snapshot(r#\"\n    [\n        1,\n    ]\n\"#);\nb.should().match_inline_snapshot(r#\"2\"#);\n",
+        );
+    }
+
+    #[test]
+    fn file_lock_should_remove_lock_of_earlier_run() {
+        let dir = TempDir::new();
+        let path = dir.join("journal.lock");
+        fs::write(&path, "").unwrap();
+
+        let rebuilt = SystemTime::now() + Duration::from_secs(1);
+        let lock = FileLock::acquire(path.clone(), rebuilt);
+
+        lock.is_ok().should().be_true();
+        drop(lock);
+        path.exists().should().be_false();
+    }
+
+    #[test]
+    fn journal_should_round_trip() {
+        let mut edits = BTreeMap::new();
+        edits.insert((3, 12), "multi\nline ✓".to_string());
+        edits.insert((7, 4), String::new());
+        let journal = Journal {
+            started: Duration::from_nanos(1_714_564_800_123_456_789),
+            original: "fn t() {}\n".to_string(),
+            edits,
+        };
+
+        Journal::decode(&journal.encode())
+            .should()
+            .eq(Some(journal));
+        Journal::decode("1 12\nshort").should().eq(&None);
+    }
+}
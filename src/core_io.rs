@@ -0,0 +1,202 @@
+use crate::Should;
+use std::fmt::Debug;
+use std::io::{self, ErrorKind};
+
+/// Describes an I/O error's kind, OS code and message for failure messages.
+fn describe_io_error(error: &io::Error) -> String {
+    let os_error = match error.raw_os_error() {
+        Some(code) => format!("OS error {}", code),
+        None => "no OS error".to_string(),
+    };
+
+    format!(
+        "kind {:?}, {}, message {:?}",
+        error.kind(),
+        os_error,
+        error.to_string()
+    )
+}
+
+impl<'a, T> Should<'a, io::Result<T>>
+where
+    T: Debug,
+{
+    /// Asserts that the Result is an I/O error of the given kind, and returns a
+    /// [`Should`] for the error.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    /// use std::io::ErrorKind;
+    ///
+    /// std::fs::read("does/not/exist")
+    ///     .should()
+    ///     .be_err_of_kind(ErrorKind::NotFound);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the Result is Ok, or the error has another kind, printing the
+    /// kind, OS code and message.
+    #[track_caller]
+    pub fn be_err_of_kind(&self, expected: ErrorKind) -> Should<'_, io::Error> {
+        match self.actual() {
+            Ok(value) => self.fail(format!(
+                "Expected Result to be Err of kind {:?}, but got Ok({:?})",
+                expected, value
+            )),
+            Err(error) => {
+                self.check(error.kind() == expected, || {
+                    format!(
+                        "Expected Result to be Err of kind {:?}, but got {}",
+                        expected,
+                        describe_io_error(error)
+                    )
+                });
+
+                Should::new(error, self.path().map(String::from))
+            }
+        }
+    }
+}
+
+impl<'a> Should<'a, io::Error> {
+    /// Asserts that the I/O error has the given kind.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    /// use std::io::{self, ErrorKind};
+    ///
+    /// let error = io::Error::new(ErrorKind::TimedOut, "no response");
+    /// error.should().have_kind(ErrorKind::TimedOut);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the error has another kind, printing the kind, OS code and
+    /// message.
+    #[track_caller]
+    pub fn have_kind(&self, expected: ErrorKind) {
+        self.check(self.actual().kind() == expected, || {
+            format!(
+                "Expected I/O error of kind {:?}, but got {}",
+                expected,
+                describe_io_error(self.actual())
+            )
+        });
+    }
+
+    /// Asserts that the I/O error carries the given raw OS error code.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    /// use std::io;
+    ///
+    /// io::Error::from_raw_os_error(2).should().have_raw_os_error(2);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the error has another OS code, or none, printing the kind, OS
+    /// code and message.
+    #[track_caller]
+    pub fn have_raw_os_error(&self, expected: i32) {
+        self.check(self.actual().raw_os_error() == Some(expected), || {
+            format!(
+                "Expected I/O error with OS error {}, but got {}",
+                expected,
+                describe_io_error(self.actual())
+            )
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Shouldable;
+    use std::io::{self, ErrorKind};
+
+    fn read_config(found: bool) -> io::Result<String> {
+        if found {
+            Ok("port = 80".to_string())
+        } else {
+            Err(io::Error::new(ErrorKind::NotFound, "config.toml missing"))
+        }
+    }
+
+    #[test]
+    fn be_err_of_kind_success_should_not_panic() {
+        read_config(false)
+            .should()
+            .be_err_of_kind(ErrorKind::NotFound)
+            .have_message_containing("config.toml");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected Result to be Err of kind PermissionDenied, but got kind NotFound, no OS error, message \"config.toml missing\""
+    )]
+    fn be_err_of_kind_wrong_kind_should_panic() {
+        read_config(false)
+            .should()
+            .be_err_of_kind(ErrorKind::PermissionDenied);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected Result to be Err of kind NotFound, but got Ok(\"port = 80\")"
+    )]
+    fn be_err_of_kind_ok_should_panic() {
+        read_config(true)
+            .should()
+            .be_err_of_kind(ErrorKind::NotFound);
+    }
+
+    #[test]
+    #[should_panic(expected = "config: Expected Result to be Err of kind Other")]
+    fn be_err_of_kind_should_include_path() {
+        read_config(false)
+            .should()
+            .named("config")
+            .be_err_of_kind(ErrorKind::Other);
+    }
+
+    #[test]
+    fn have_kind_success_should_not_panic() {
+        io::Error::new(ErrorKind::TimedOut, "no response")
+            .should()
+            .have_kind(ErrorKind::TimedOut);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected I/O error of kind NotFound, but got kind TimedOut, no OS error, message \"no response\""
+    )]
+    fn have_kind_fail_should_panic() {
+        io::Error::new(ErrorKind::TimedOut, "no response")
+            .should()
+            .have_kind(ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn have_raw_os_error_success_should_not_panic() {
+        io::Error::from_raw_os_error(2)
+            .should()
+            .have_raw_os_error(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected I/O error with OS error 13, but got kind")]
+    fn have_raw_os_error_wrong_code_should_panic() {
+        io::Error::from_raw_os_error(2)
+            .should()
+            .have_raw_os_error(13);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected I/O error with OS error 2, but got kind Other, no OS error"
+    )]
+    fn have_raw_os_error_without_code_should_panic() {
+        io::Error::other("boom").should().have_raw_os_error(2);
+    }
+}
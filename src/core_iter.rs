@@ -0,0 +1,311 @@
+use crate::{ShouldMut, ShouldOwned, Shouldable};
+use std::fmt::Debug;
+
+impl<'a, I> ShouldMut<'a, I>
+where
+    I: Iterator + ?Sized,
+    I::Item: Debug,
+{
+    /// Pulls at most `limit` items from the iterator.
+    #[track_caller]
+    fn take_items(&mut self, limit: usize) -> Vec<I::Item> {
+        self.actual_mut().take(limit).collect()
+    }
+
+    /// Asserts that the iterator yields the expected items next, in order.
+    ///
+    /// Only `expected.len()` items are consumed, so this also works as a prefix
+    /// check on infinite iterators. Chain [`Should::be_exhausted`] to also check
+    /// that nothing follows.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// (1..).map(|x| x * 2).into_should().yield_items(&[2, 4, 6]);
+    ///
+    /// vec![1, 2].into_iter().into_should().yield_items(&[1, 2]).be_exhausted();
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if an item differs or the iterator ends early, listing the items
+    /// produced so far.
+    #[track_caller]
+    pub fn yield_items<T>(&mut self, expected: &[T]) -> &mut Self
+    where
+        I::Item: PartialEq<T>,
+        T: Debug,
+    {
+        let mut produced = Vec::new();
+
+        for item in expected {
+            let next = self.actual_mut().next();
+
+            match next {
+                Some(actual) => {
+                    let matches = actual == *item;
+                    produced.push(actual);
+
+                    if !matches {
+                        self.fail(format!(
+                            "Expected iterator to yield {:?}, but it yielded {:?}",
+                            expected, produced
+                        ));
+                    }
+                }
+                None => self.fail(format!(
+                    "Expected iterator to yield {:?}, but it ended after {:?}",
+                    expected, produced
+                )),
+            }
+        }
+
+        self
+    }
+
+    /// Asserts that the iterator yields exactly `expected` more items.
+    ///
+    /// At most `expected + 1` items are consumed.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// "a,b,c".split(',').into_should().yield_count(3);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the iterator yields fewer or more items, listing the items
+    /// produced.
+    #[track_caller]
+    pub fn yield_count(&mut self, expected: usize) -> &mut Self {
+        let produced = self.take_items(expected.saturating_add(1));
+
+        if produced.len() > expected {
+            self.fail(format!(
+                "Expected iterator to yield {} item(s), but it yielded at least {}: {:?}",
+                expected,
+                produced.len(),
+                produced
+            ));
+        }
+
+        self.check(produced.len() == expected, || {
+            format!(
+                "Expected iterator to yield {} item(s), but it yielded {}: {:?}",
+                expected,
+                produced.len(),
+                produced
+            )
+        });
+
+        self
+    }
+
+    /// Asserts that the iterator yields at least `expected` more items.
+    ///
+    /// Exactly `expected` items are consumed, so infinite iterators pass.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// (0..).into_should().yield_at_least(1_000);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the iterator ends early, listing the items produced.
+    #[track_caller]
+    pub fn yield_at_least(&mut self, expected: usize) -> &mut Self {
+        let produced = self.take_items(expected);
+
+        self.check(produced.len() == expected, || {
+            format!(
+                "Expected iterator to yield at least {} item(s), but it yielded {}: {:?}",
+                expected,
+                produced.len(),
+                produced
+            )
+        });
+
+        self
+    }
+
+    /// Asserts that the iterator yields no more items.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// std::iter::empty::<i32>().into_should().be_exhausted();
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the iterator yields another item, printing it.
+    #[track_caller]
+    pub fn be_exhausted(&mut self) -> &mut Self {
+        let next = self.actual_mut().next();
+
+        if let Some(item) = next {
+            self.fail(format!(
+                "Expected iterator to be exhausted, but it yielded {:?}",
+                item
+            ));
+        }
+
+        self
+    }
+
+    /// Asserts that the iterator yields another item and returns a [`Should`]
+    /// for it.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// vec![3, 1, 2].into_iter().into_should().first_item().eq(&3);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the iterator is exhausted.
+    #[track_caller]
+    pub fn first_item(&mut self) -> ShouldOwned<'a, I::Item>
+    where
+        I::Item: 'a,
+    {
+        let next = self.actual_mut().next();
+
+        match next {
+            Some(item) => {
+                let should = item.into_should();
+
+                match self.path() {
+                    Some(path) => should.named(path),
+                    None => should,
+                }
+            }
+            None => self.fail("Expected iterator to yield an item, but it was exhausted"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Shouldable;
+
+    #[test]
+    fn yield_items_success_should_not_panic() {
+        vec![1, 2, 3]
+            .into_iter()
+            .into_should()
+            .yield_items(&[1, 2, 3]);
+    }
+
+    #[test]
+    fn yield_items_infinite_should_not_panic() {
+        (1..).map(|x| x * 2).into_should().yield_items(&[2, 4, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected iterator to yield [1, 2, 3], but it yielded [1, 5]")]
+    fn yield_items_mismatch_should_panic() {
+        vec![1, 5, 3]
+            .into_iter()
+            .into_should()
+            .yield_items(&[1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected iterator to yield [1, 2, 3], but it ended after [1]")]
+    fn yield_items_ended_should_panic() {
+        vec![1].into_iter().into_should().yield_items(&[1, 2, 3]);
+    }
+
+    #[test]
+    fn yield_items_should_only_consume_needed_items() {
+        let mut items = vec![1, 2, 3].into_iter();
+        items.should_mut().yield_items(&[1]);
+        items.len().should().eq(&2);
+    }
+
+    #[test]
+    fn yield_items_heterogeneous_should_not_panic() {
+        let words = vec!["a".to_string(), "b".to_string()];
+        words.into_iter().into_should().yield_items(&["a", "b"]);
+    }
+
+    #[test]
+    fn yield_count_success_should_not_panic() {
+        "a,b,c".split(',').into_should().yield_count(3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected iterator to yield 2 item(s), but it yielded at least 3: [0, 1, 2]"
+    )]
+    fn yield_count_too_many_should_panic() {
+        (0..).into_should().yield_count(2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected iterator to yield 4 item(s), but it yielded 3: [\"a\", \"b\", \"c\"]"
+    )]
+    fn yield_count_too_few_should_panic() {
+        "a,b,c".split(',').into_should().yield_count(4);
+    }
+
+    #[test]
+    fn yield_at_least_success_should_not_panic() {
+        (0..).into_should().yield_at_least(1_000);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected iterator to yield at least 3 item(s), but it yielded 2: [1, 2]"
+    )]
+    fn yield_at_least_fail_should_panic() {
+        vec![1, 2].into_iter().into_should().yield_at_least(3);
+    }
+
+    #[test]
+    fn be_exhausted_success_should_not_panic() {
+        vec![1, 2]
+            .into_iter()
+            .into_should()
+            .yield_items(&[1, 2])
+            .be_exhausted();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected iterator to be exhausted, but it yielded 3")]
+    fn be_exhausted_fail_should_panic() {
+        vec![1, 2, 3]
+            .into_iter()
+            .into_should()
+            .yield_items(&[1, 2])
+            .be_exhausted();
+    }
+
+    #[test]
+    fn first_item_success_should_not_panic() {
+        vec![3, 1, 2].into_iter().into_should().first_item().eq(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "values: Expected 3 to equal to 4")]
+    fn first_item_should_keep_path() {
+        vec![3, 1, 2]
+            .into_iter()
+            .into_should()
+            .named("values")
+            .first_item()
+            .eq(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected iterator to yield an item, but it was exhausted")]
+    fn first_item_empty_should_panic() {
+        std::iter::empty::<i32>().into_should().first_item();
+    }
+}
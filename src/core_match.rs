@@ -0,0 +1,623 @@
+use crate::{Matcher, Should, ShouldMut, ShouldOwned};
+use std::fmt::Debug;
+use std::ops::{Range, RangeBounds, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+
+/// Asserts that a struct value matches a partial pattern, checking only the listed fields.
+///
+/// Each field can be given as:
+///
+/// - a value, compared with `==` (e.g. `name: "x"`),
+/// - a range, checked with `contains` (e.g. `age: 3..`),
+/// - a [`Matcher`](crate::Matcher) (e.g. `age: not(eq(0))`),
+/// - a predicate closure (e.g. `name: |n| n.starts_with('x')`),
+/// - a nested struct pattern (e.g. `address: Address { city: "Oslo", .. }`).
+///
+/// As in a Rust pattern, fields that are not listed are ignored when the
+/// pattern ends with `..`, which makes it possible to assert on values with
+/// generated IDs or timestamps. Without `..`, every field must be listed.
+///
+/// The value can also be a [`Should`](crate::Should) or
+/// [`ShouldMut`](crate::ShouldMut), whose breadcrumb path then prefixes the
+/// failure message.
+///
+/// # Examples
+/// ```
+/// use shoulds::should_match;
+///
+/// struct Address {
+///     city: String,
+/// }
+///
+/// struct User {
+///     id: u64,
+///     name: String,
+///     age: u32,
+///     address: Address,
+/// }
+///
+/// let user = User {
+///     id: 8_172_361,
+///     name: "Kari".to_string(),
+///     age: 42,
+///     address: Address {
+///         city: "Oslo".to_string(),
+///     },
+/// };
+///
+/// should_match!(user, User {
+///     name: "Kari",
+///     age: 18..,
+///     address: Address { city: |c| c.starts_with('O') },
+///     ..
+/// });
+/// ```
+///
+/// Leaving out a field without `..` fails to compile:
+///
+/// ```compile_fail
+/// use shoulds::should_match;
+///
+/// struct User {
+///     id: u64,
+///     name: String,
+/// }
+///
+/// let user = User { id: 1, name: "Kari".to_string() };
+/// should_match!(user, User { name: "Kari" });
+/// ```
+///
+/// # Panics
+/// Panics if any listed field does not match, reporting every mismatching
+/// field path, e.g. `.address.city: expected "Oslo" but was "Bergen"`.
+#[macro_export]
+macro_rules! should_match {
+    ($actual:expr, $($pattern:tt)+) => {
+        match &$actual {
+            __subject => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ShouldSubject as _, ValueSubject as _};
+
+                let (__value, __path) = (&&$crate::__private::Subject(__subject)).parts();
+                let mut __mismatches: ::std::vec::Vec<$crate::Difference> = ::std::vec::Vec::new();
+                $crate::__should_match!(@pattern __value, "", __mismatches, $($pattern)+);
+
+                if !__mismatches.is_empty() {
+                    let __lines: ::std::vec::Vec<::std::string::String> = __mismatches
+                        .iter()
+                        .map(|mismatch| ::std::format!("  {}", mismatch))
+                        .collect();
+
+                    $crate::__private::should_at(__value, __path).fail(::std::format!(
+                        "Expected value to match pattern, but found {} mismatch(es):\n{}",
+                        __mismatches.len(),
+                        __lines.join("\n")
+                    ));
+                }
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __should_match {
+    (@pattern $actual:expr, $path:expr, $out:ident, $($ty:ident)::+ { $($fields:tt)* }) => {{
+        let __value = $actual;
+        $crate::__should_match!(@fields __value, [$($ty)::+] [], $path, $out, $($fields)*);
+    }};
+
+    (@fields $value:ident, [$($ty:ident)::+] [$($seen:ident)*], $path:expr, $out:ident, $(,)?) => {
+        if false {
+            let $($ty)::+ { $($seen: _),* } = $value;
+        }
+    };
+
+    (@fields $value:ident, [$($ty:ident)::+] [$($seen:ident)*], $path:expr, $out:ident, .. $(,)?) => {
+        if false {
+            let $($ty)::+ { .. } = $value;
+        }
+    };
+
+    (@fields $value:ident, [$($ty:ident)::+] [$($seen:ident)*], $path:expr, $out:ident,
+        $field:ident : $($inner_ty:ident)::+ { $($inner:tt)* } $(, $($rest:tt)*)?
+    ) => {
+        $crate::__should_match!(
+            @pattern &$value.$field,
+            &::std::format!("{}.{}", $path, ::std::stringify!($field)),
+            $out,
+            $($inner_ty)::+ { $($inner)* }
+        );
+        $crate::__should_match!(
+            @fields $value, [$($ty)::+] [$($seen)* $field], $path, $out, $($($rest)*)?
+        );
+    };
+
+    (@fields $value:ident, [$($ty:ident)::+] [$($seen:ident)*], $path:expr, $out:ident,
+        $field:ident : | $arg:pat_param | $body:expr $(, $($rest:tt)*)?
+    ) => {
+        {
+            let __actual = &$value.$field;
+            let $arg = __actual;
+            if !$body {
+                $out.push($crate::Difference::new(
+                    &::std::format!("{}.{}", $path, ::std::stringify!($field)),
+                    ::std::format!(
+                        "to satisfy `|{}| {}`",
+                        ::std::stringify!($arg),
+                        ::std::stringify!($body)
+                    ),
+                    ::std::format!("{:?}", __actual),
+                ));
+            }
+        }
+        $crate::__should_match!(
+            @fields $value, [$($ty)::+] [$($seen)* $field], $path, $out, $($($rest)*)?
+        );
+    };
+
+    (@fields $value:ident, [$($ty:ident)::+] [$($seen:ident)*], $path:expr, $out:ident,
+        $field:ident : $expected:expr $(, $($rest:tt)*)?
+    ) => {
+        {
+            #[allow(unused_imports)]
+            use $crate::__private::{EqPattern as _, MatcherPattern as _, RangePattern as _};
+
+            let __actual = &$value.$field;
+            let __expected = $crate::__private::Pattern($expected);
+            if let ::std::option::Option::Some(__description) = (&&&__expected).check(__actual) {
+                $out.push($crate::Difference::new(
+                    &::std::format!("{}.{}", $path, ::std::stringify!($field)),
+                    __description,
+                    ::std::format!("{:?}", __actual),
+                ));
+            }
+        }
+        $crate::__should_match!(
+            @fields $value, [$($ty)::+] [$($seen)* $field], $path, $out, $($($rest)*)?
+        );
+    };
+}
+
+/// Asserts that a value matches a pattern, typically an enum variant.
+///
+/// An optional `=> binding` hands a payload bound by the pattern to a new
+/// [`Should`](crate::Should) for further assertions. Bindings are references,
+/// as the value is matched by reference.
+///
+/// The value can also be a [`Should`](crate::Should) or
+/// [`ShouldMut`](crate::ShouldMut), whose breadcrumb path then prefixes the
+/// failure message and carries over to the binding.
+///
+/// # Examples
+/// ```
+/// use shoulds::be_variant;
+///
+/// #[derive(Debug)]
+/// enum Event {
+///     Started,
+///     Progress(u8),
+/// }
+///
+/// let event = Event::Progress(42);
+/// be_variant!(event, Event::Progress(_));
+/// be_variant!(event, Event::Progress(p) if *p > 40);
+/// be_variant!(event, Event::Progress(p) => p).eq(&42);
+/// ```
+///
+/// # Panics
+/// Panics if the value does not match the pattern, printing the actual value.
+#[macro_export]
+macro_rules! be_variant {
+    ($actual:expr, $pattern:pat $(if $guard:expr)? => $binding:expr $(,)?) => {
+        match &$actual {
+            __subject => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ShouldSubject as _, ValueSubject as _};
+
+                let (__value, __path) = (&&$crate::__private::Subject(__subject)).parts();
+                match __value {
+                    $pattern $(if $guard)? => $crate::__private::should_at($binding, __path),
+                    __actual => $crate::__private::should_at(__actual, __path).fail(::std::format!(
+                        "Expected {:?} to match pattern {}",
+                        __actual,
+                        ::std::stringify!($pattern $(if $guard)?)
+                    )),
+                }
+            }
+        }
+    };
+
+    ($actual:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {
+        match &$actual {
+            __subject => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ShouldSubject as _, ValueSubject as _};
+
+                let (__value, __path) = (&&$crate::__private::Subject(__subject)).parts();
+                match __value {
+                    $pattern $(if $guard)? => {}
+                    __actual => $crate::__private::should_at(__actual, __path).fail(::std::format!(
+                        "Expected {:?} to match pattern {}",
+                        __actual,
+                        ::std::stringify!($pattern $(if $guard)?)
+                    )),
+                }
+            }
+        }
+    };
+}
+
+/// Wraps the value given to [`should_match!`](crate::should_match) or [`be_variant!`](crate::be_variant) so that a
+/// [`Should`] and a plain value can be told apart by method resolution.
+#[doc(hidden)]
+pub struct Subject<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait ShouldSubject<'a> {
+    type Target: ?Sized;
+
+    /// Returns the value under test and its breadcrumb path.
+    fn parts(&self) -> (&'a Self::Target, Option<String>);
+}
+
+impl<'a, 's, T: ?Sized> ShouldSubject<'a> for &Subject<'a, Should<'s, T>> {
+    type Target = T;
+
+    fn parts(&self) -> (&'a T, Option<String>) {
+        let should: &'a Should<'s, T> = self.0;
+        (should.actual(), should.path().map(str::to_string))
+    }
+}
+
+impl<'a, 's, T: ?Sized> ShouldSubject<'a> for &Subject<'a, ShouldMut<'s, T>> {
+    type Target = T;
+
+    fn parts(&self) -> (&'a T, Option<String>) {
+        let should: &'a Should<'s, T> = self.0;
+        (should.actual(), should.path().map(str::to_string))
+    }
+}
+impl<'a, 's, T: ?Sized> ShouldSubject<'a> for &Subject<'a, ShouldOwned<'s, T>> {
+    type Target = T;
+
+    fn parts(&self) -> (&'a T, Option<String>) {
+        let should: &'a Should<'s, T> = self.0;
+        (should.actual(), should.path().map(str::to_string))
+    }
+}
+
+#[doc(hidden)]
+pub trait ValueSubject<'a> {
+    type Target: ?Sized;
+
+    /// Returns the value under test and its breadcrumb path.
+    fn parts(&self) -> (&'a Self::Target, Option<String>);
+}
+
+impl<'a, T: ?Sized> ValueSubject<'a> for Subject<'a, T> {
+    type Target = T;
+
+    fn parts(&self) -> (&'a T, Option<String>) {
+        (self.0, None)
+    }
+}
+
+/// Creates a [`Should`] for a value matched by [`should_match!`] or
+/// [`be_variant!`], keeping the breadcrumb path of the original value.
+#[doc(hidden)]
+pub fn should_at<T: ?Sized>(value: &T, path: Option<String>) -> Should<'_, T> {
+    Should::new(value, path)
+}
+
+/// Wraps the expected side of a field pattern so the matching strategy can be
+/// picked by method resolution: matchers first, then ranges, then equality.
+#[doc(hidden)]
+pub struct Pattern<P>(pub P);
+
+#[doc(hidden)]
+pub trait MatcherPattern<T: ?Sized> {
+    /// Returns a description of the expectation when `actual` does not match.
+    fn check(&self, actual: &T) -> Option<String>;
+}
+
+impl<T, M> MatcherPattern<T> for &&Pattern<M>
+where
+    T: Debug + ?Sized,
+    M: Matcher<T>,
+{
+    fn check(&self, actual: &T) -> Option<String> {
+        if self.0.check(actual) {
+            None
+        } else {
+            Some(self.0.describe())
+        }
+    }
+}
+
+/// Implemented for the standard range types usable in field patterns.
+#[doc(hidden)]
+pub trait RangeLike {}
+
+impl<T> RangeLike for Range<T> {}
+impl<T> RangeLike for RangeFrom<T> {}
+impl<T> RangeLike for RangeInclusive<T> {}
+impl<T> RangeLike for RangeTo<T> {}
+impl<T> RangeLike for RangeToInclusive<T> {}
+
+#[doc(hidden)]
+pub trait RangePattern<R> {
+    /// Returns a description of the expectation when `actual` does not match.
+    fn check<T>(&self, actual: &T) -> Option<String>
+    where
+        T: PartialOrd + Debug,
+        R: RangeBounds<T>;
+}
+
+impl<R> RangePattern<R> for &Pattern<R>
+where
+    R: RangeLike + Debug,
+{
+    fn check<T>(&self, actual: &T) -> Option<String>
+    where
+        T: PartialOrd + Debug,
+        R: RangeBounds<T>,
+    {
+        if self.0.contains(actual) {
+            None
+        } else {
+            Some(format!("in range {:?}", self.0))
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait EqPattern<U> {
+    /// Returns a description of the expectation when `actual` does not match.
+    fn check<T>(&self, actual: &T) -> Option<String>
+    where
+        T: PartialEq<U> + ?Sized;
+}
+
+impl<U> EqPattern<U> for Pattern<U>
+where
+    U: Debug,
+{
+    fn check<T>(&self, actual: &T) -> Option<String>
+    where
+        T: PartialEq<U> + ?Sized,
+    {
+        if *actual == self.0 {
+            None
+        } else {
+            Some(format!("{:?}", self.0))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug)]
+    struct Address {
+        city: String,
+        zip: u32,
+    }
+
+    #[derive(Debug)]
+    struct User {
+        id: u64,
+        name: String,
+        age: u32,
+        score: f64,
+        tags: Vec<&'static str>,
+        address: Address,
+    }
+
+    fn user() -> User {
+        User {
+            id: 8_172_361,
+            name: "Kari".to_string(),
+            age: 42,
+            score: 0.5,
+            tags: vec!["admin"],
+            address: Address {
+                city: "Bergen".to_string(),
+                zip: 5003,
+            },
+        }
+    }
+
+    #[test]
+    fn should_match_success_should_not_panic() {
+        should_match!(
+            user(),
+            User {
+                name: "Kari",
+                age: 18..,
+                score: 0.0..=1.0,
+                tags: vec!["admin"],
+                address: Address {
+                    city: |c| c.starts_with('B'),
+                    ..
+                },
+                ..
+            }
+        );
+    }
+
+    #[test]
+    fn should_match_without_rest_listing_every_field_should_not_panic() {
+        should_match!(
+            user(),
+            User {
+                id: 8_172_361,
+                name: "Kari",
+                age: 42,
+                score: 0.5,
+                tags: vec!["admin"],
+                address: Address {
+                    city: "Bergen",
+                    zip: 5003,
+                },
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "user: Expected value to match pattern, but found 1 mismatch(es):\n  .age: expected 41 but was 42"
+    )]
+    fn should_match_should_prefix_should_path() {
+        use crate::Shouldable;
+
+        should_match!(user().into_should().named("user"), User { age: 41, .. });
+    }
+
+    #[test]
+    fn should_match_with_matchers_success_should_not_panic() {
+        use crate::matchers::{all_of, ne, not};
+
+        should_match!(
+            user(),
+            User {
+                age: all_of((ne(0), ne(1))),
+                name: not(ne("Kari")),
+                ..
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = ".age: expected any of (equal to 1 or equal to 2) but was 42")]
+    fn should_match_with_matchers_fail_should_panic() {
+        use crate::matchers::{any_of, eq};
+
+        should_match!(
+            user(),
+            User {
+                age: any_of((eq(1), eq(2))),
+                ..
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = ".name: expected \"Ola\" but was \"Kari\"")]
+    fn should_match_value_fail_should_panic() {
+        should_match!(user(), User { name: "Ola", .. });
+    }
+
+    #[test]
+    #[should_panic(expected = ".age: expected in range 50..60 but was 42")]
+    fn should_match_range_fail_should_panic() {
+        should_match!(user(), User { age: 50..60, .. });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = ".address.city: expected to satisfy `|c| c.is_empty()` but was \"Bergen\""
+    )]
+    fn should_match_predicate_fail_should_panic() {
+        should_match!(
+            user(),
+            User {
+                address: Address {
+                    city: |c| c.is_empty(),
+                    ..
+                },
+                ..
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "found 2 mismatch(es):\n  .age: expected 41 but was 42\n  .address.zip: expected 5000 but was 5003"
+    )]
+    fn should_match_should_report_every_mismatch() {
+        should_match!(
+            user(),
+            User {
+                name: "Kari",
+                age: 41,
+                address: Address { zip: 5000, .. },
+                ..
+            }
+        );
+    }
+
+    #[derive(Debug)]
+    enum MyEnum {
+        Variant1,
+        Variant2(i32),
+        Variant3 { name: String },
+    }
+
+    #[test]
+    fn be_variant_success_should_not_panic() {
+        let value = MyEnum::Variant2(42);
+        be_variant!(value, MyEnum::Variant2(_));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Variant1 to match pattern MyEnum::Variant2(_)")]
+    fn be_variant_fail_should_panic() {
+        let value = MyEnum::Variant1;
+        be_variant!(value, MyEnum::Variant2(_));
+    }
+
+    #[test]
+    fn be_variant_with_guard_success_should_not_panic() {
+        let value = MyEnum::Variant2(42);
+        be_variant!(value, MyEnum::Variant2(n) if *n > 40);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected Variant2(42) to match pattern MyEnum::Variant2(n) if *n < 40"
+    )]
+    fn be_variant_with_guard_fail_should_panic() {
+        let value = MyEnum::Variant2(42);
+        be_variant!(value, MyEnum::Variant2(n) if *n < 40);
+    }
+
+    #[test]
+    fn be_variant_binding_should_chain() {
+        let value = MyEnum::Variant3 {
+            name: "test".to_string(),
+        };
+        be_variant!(value, MyEnum::Variant3 { name } => name).eq("test".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 42 to equal to 43")]
+    fn be_variant_binding_fail_should_panic() {
+        let value = MyEnum::Variant2(42);
+        be_variant!(value, MyEnum::Variant2(n) => n).eq(43);
+    }
+
+    #[test]
+    #[should_panic(expected = "event: Expected Variant1 to match pattern MyEnum::Variant2(_)")]
+    fn be_variant_should_prefix_should_path() {
+        use crate::Shouldable;
+
+        let value = MyEnum::Variant1;
+        be_variant!(value.should().named("event"), MyEnum::Variant2(_));
+    }
+
+    #[test]
+    #[should_panic(expected = "event: Expected 42 to equal to 43")]
+    fn be_variant_binding_should_keep_should_path() {
+        use crate::Shouldable;
+
+        let value = MyEnum::Variant2(42);
+        let event = value.should().named("event");
+        be_variant!(event, MyEnum::Variant2(n) => n).eq(43);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Variant1 to match pattern MyEnum::Variant3 { name }")]
+    fn be_variant_binding_wrong_variant_should_panic() {
+        let value = MyEnum::Variant1;
+        be_variant!(value, MyEnum::Variant3 { name } => name).eq("test".to_string());
+    }
+}
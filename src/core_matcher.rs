@@ -0,0 +1,82 @@
+use crate::matchers::Matcher;
+use crate::Should;
+use std::fmt::Debug;
+
+impl<'a, T> Should<'a, T>
+where
+    T: Debug + ?Sized,
+{
+    /// Asserts that the value satisfies the given [`Matcher`].
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::matchers::{any_of, eq};
+    /// use shoulds::Shouldable;
+    ///
+    /// let value = 42;
+    /// value.should().match_with(any_of((eq(41), eq(42))));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the matcher does not match, describing both the expectation
+    /// and the mismatch.
+    pub fn match_with<M: Matcher<T>>(&self, matcher: M) {
+        self.check(matcher.check(self.actual()), || {
+            format!(
+                "Expected {}, but {}",
+                matcher.describe(),
+                matcher.describe_mismatch(self.actual())
+            )
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matchers::{all_of, any_of, each, eq, err, ne, not, ok, some};
+    use crate::Shouldable;
+
+    #[test]
+    fn match_with_success_should_not_panic() {
+        let value = 42;
+        value.should().match_with(eq(42));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected equal to 43, but was 42")]
+    fn match_with_fail_should_panic() {
+        let value = 42;
+        value.should().match_with(eq(43));
+    }
+
+    #[test]
+    fn match_with_combinators_success_should_not_panic() {
+        let value = Some(3);
+        value
+            .should()
+            .match_with(all_of((some(), not(eq(Some(4))), ne(None))));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected any of (Ok or equal to Err(\"retry\")), but was Err(\"fatal\")"
+    )]
+    fn match_with_any_of_fail_should_panic() {
+        let value: Result<i32, &str> = Err("fatal");
+        value.should().match_with(any_of((ok(), eq(Err("retry")))));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected each item not Err, but item [1] was Err(\"e\")")]
+    fn match_with_each_fail_should_panic() {
+        let value: Vec<Result<i32, &str>> = vec![Ok(1), Err("e")];
+        value.should().match_with(each(not(err())));
+    }
+
+    #[test]
+    #[should_panic(expected = "total: Expected not equal to 0, but was 0")]
+    fn match_with_should_include_path() {
+        let value = 0;
+        value.should().named("total").match_with(ne(0));
+    }
+}
@@ -1,30 +1,42 @@
-use crate::Should;
+use crate::matchers::{self, Matcher};
+use crate::{Expected, Should};
 use std::fmt::Debug;
 
 impl<'a, T> Should<'a, T>
 where
-    T: PartialEq + Debug,
+    T: Debug + ?Sized,
 {
     /// Asserts that the value is not equal to the expected value.
     ///
+    /// As with [`Should::eq`], the expected value can be of any comparable type
+    /// and passed by reference or by value.
+    ///
     /// # Examples
     /// ```
     /// use shoulds::Shouldable;
     ///
     /// let value = 42;
     /// value.should().ne(&43);
+    /// value.should().ne(43);
     /// ```
     ///
     /// # Panics
     /// Panics if the actual value is equal to the expected value.
-    pub fn ne(&self, expected: &T) {
-        assert_ne!(
-            self.actual(),
-            expected,
-            "Expected {:?} not to eq equal to {:?}",
-            self.actual(),
-            expected
-        );
+    pub fn ne<E, U>(&self, expected: E)
+    where
+        E: Expected<U>,
+        T: PartialEq<U>,
+        U: Debug + ?Sized,
+    {
+        let expected = expected.expected();
+
+        self.check(matchers::ne(expected).check(&self.actual()), || {
+            format!(
+                "Expected {:?} not to equal to {:?}",
+                self.actual(),
+                expected
+            )
+        });
     }
 }
 
@@ -35,66 +47,88 @@ mod tests {
     #[test]
     fn ne_i32_success_should_not_panic() {
         let value = 42;
-        value.should().ne(&43);
+        value.should().ne(43);
     }
 
     #[test]
-    #[should_panic(expected = "Expected 42 not to eq equal to 42")]
+    #[should_panic(expected = "Expected 42 not to equal to 42")]
     fn ne_i32_fail_should_panic() {
         let value = 42;
-        value.should().ne(&42);
+        value.should().ne(42);
     }
 
     #[test]
+    fn ne_by_value_success_should_not_panic() {
+        let name = "Hello".to_string();
+        name.should().ne("World".to_string());
+        name.should().ne("World");
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
     fn ne_f64_success_should_not_panic() {
         let value = 3.14;
-        value.should().ne(&2.71);
+        value.should().ne(2.71);
     }
 
     #[test]
-    #[should_panic(expected = "Expected 3.14 not to eq equal to 3.14")]
+    #[should_panic(expected = "Expected 3.14 not to equal to 3.14")]
+    #[allow(clippy::approx_constant)]
     fn ne_f64_fail_should_panic() {
         let value = 3.14;
-        value.should().ne(&3.14);
+        value.should().ne(3.14);
     }
 
     #[test]
     fn ne_string_success_should_not_panic() {
         let value = "Hello".to_string();
-        value.should().ne(&"World".to_string());
+        value.should().ne("World".to_string());
     }
 
     #[test]
-    #[should_panic(expected = "Expected \"Hello\" not to eq equal to \"Hello\"")]
+    #[should_panic(expected = "Expected \"Hello\" not to equal to \"Hello\"")]
     fn ne_string_fail_should_panic() {
         let value = "Hello".to_string();
-        value.should().ne(&"Hello".to_string());
+        value.should().ne("Hello".to_string());
+    }
+
+    #[test]
+    fn ne_string_str_success_should_not_panic() {
+        let value = "Hello".to_string();
+        value.should().ne("World");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected \"Hello\" not to equal to \"Hello\"")]
+    fn ne_string_str_fail_should_panic() {
+        let value = "Hello".to_string();
+        value.should().ne("Hello");
     }
 
     #[test]
     fn ne_char_success_should_not_panic() {
         let value = 'a';
-        value.should().ne(&'b');
+        value.should().ne('b');
     }
 
     #[test]
-    #[should_panic(expected = "Expected 'a' not to eq equal to 'a'")]
+    #[should_panic(expected = "Expected 'a' not to equal to 'a'")]
     fn ne_char_fail_should_panic() {
         let value = 'a';
-        value.should().ne(&'a');
+        value.should().ne('a');
     }
 
     #[test]
     fn ne_bool_success_should_not_panic() {
         let value = true;
-        value.should().ne(&false);
+        value.should().ne(false);
     }
 
     #[test]
-    #[should_panic(expected = "Expected true not to eq equal to true")]
+    #[should_panic(expected = "Expected true not to equal to true")]
     fn ne_bool_fail_should_panic() {
         let value = true;
-        value.should().ne(&true);
+        value.should().ne(true);
     }
 
     #[test]
@@ -104,7 +138,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Expected (1, 2) not to eq equal to (1, 2)")]
+    #[should_panic(expected = "Expected (1, 2) not to equal to (1, 2)")]
     fn ne_tuple_fail_should_panic() {
         let value = (1, 2);
         value.should().ne(&(1, 2));
@@ -117,7 +151,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Expected [1, 2, 3] not to eq equal to [1, 2, 3]")]
+    #[should_panic(expected = "Expected [1, 2, 3] not to equal to [1, 2, 3]")]
     fn ne_vector_fail_should_panic() {
         let value = vec![1, 2, 3];
         value.should().ne(&vec![1, 2, 3]);
@@ -130,7 +164,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Expected [1, 2, 3] not to eq equal to [1, 2, 3]")]
+    #[should_panic(expected = "Expected [1, 2, 3] not to equal to [1, 2, 3]")]
     fn ne_array_fail_should_panic() {
         let value = [1, 2, 3];
         value.should().ne(&[1, 2, 3]);
@@ -149,7 +183,7 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "Expected MyStruct { value: 1 } not to eq equal to MyStruct { value: 1 }"
+        expected = "Expected MyStruct { value: 1 } not to equal to MyStruct { value: 1 }"
     )]
     fn ne_struct_fail_should_panic() {
         #[derive(PartialEq, Debug)]
@@ -182,7 +216,7 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "Expected CustomType { id: 1, name: \"Test\" } not to eq equal to CustomType { id: 1, name: \"Test\" }"
+        expected = "Expected CustomType { id: 1, name: \"Test\" } not to equal to CustomType { id: 1, name: \"Test\" }"
     )]
     fn ne_custom_type_fail_should_panic() {
         #[derive(PartialEq, Debug)]
@@ -215,7 +249,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Expected Variant1 not to eq equal to Variant1")]
+    #[should_panic(expected = "Expected Variant1 not to equal to Variant1")]
     fn ne_enum_fail_should_panic() {
         #[derive(PartialEq, Debug)]
         enum MyEnum {
@@ -233,14 +267,14 @@ mod tests {
     #[test]
     fn ne_option_success_should_not_panic() {
         let value = Some(42);
-        value.should().ne(&None);
+        value.should().ne(None);
     }
 
     #[test]
-    #[should_panic(expected = "Expected Some(42) not to eq equal to Some(42)")]
+    #[should_panic(expected = "Expected Some(42) not to equal to Some(42)")]
     fn ne_option_fail_should_panic() {
         let value = Some(42);
-        value.should().ne(&Some(42));
+        value.should().ne(Some(42));
     }
 
     #[test]
@@ -250,7 +284,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Expected Ok(42) not to eq equal to Ok(42)")]
+    #[should_panic(expected = "Expected Ok(42) not to equal to Ok(42)")]
     fn ne_result_fail_should_panic() {
         let value: Result<i32, &str> = Ok(42);
         value.should().ne(&Ok(42));
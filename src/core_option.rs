@@ -1,3 +1,4 @@
+use crate::matchers::{self, Matcher};
 use crate::Should;
 use std::fmt::Debug;
 
@@ -18,10 +19,9 @@ where
     /// # Panics
     /// Panics if the Option is None.
     pub fn be_some(&self) {
-        assert!(
-            self.actual().is_some(),
+        self.check(matchers::some().check(self.actual()), || {
             "Expected Option to be Some, but got None"
-        )
+        })
     }
 
     /// Asserts that the Option is None.
@@ -37,11 +37,9 @@ where
     /// # Panics
     /// Panics if the Option is Some.
     pub fn be_none(&self) {
-        assert!(
-            self.actual().is_none(),
-            "Expected Option to be None, but got Some({:?})",
-            self.actual()
-        );
+        self.check(matchers::none().check(self.actual()), || {
+            format!("Expected Option to be None, but got {:?}", self.actual())
+        });
     }
 }
 
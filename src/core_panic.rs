@@ -0,0 +1,413 @@
+use crate::{Should, ShouldOwned, Shouldable};
+use std::any::{type_name, Any};
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+thread_local! {
+    static SILENCED: Cell<bool> = const { Cell::new(false) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Runs `f`, catching any panic without printing the default panic output.
+///
+/// The panic hook is wrapped once per process; panics on other threads, and
+/// panics outside of `catch_panic`, are still reported by the original hook.
+pub(crate) fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, Box<dyn Any + Send>> {
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !SILENCED.with(Cell::get) {
+                previous(info);
+            }
+        }));
+    });
+
+    let was_silenced = SILENCED.with(|silenced| silenced.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    SILENCED.with(|silenced| silenced.set(was_silenced));
+
+    result
+}
+
+/// Returns the message of a panic payload, if it is a string.
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> Option<&str> {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+}
+
+/// Describes a panic payload for failure messages.
+pub(crate) fn describe_panic(payload: &(dyn Any + Send)) -> String {
+    match panic_message(payload) {
+        Some(message) => format!("{:?}", message),
+        None => "a non-string payload".to_string(),
+    }
+}
+
+/// Fails unless calling `f` panics.
+#[track_caller]
+fn expect_panic<T: ?Sized, R>(should: &Should<'_, T>, f: impl FnOnce() -> R) {
+    if catch_panic(f).is_ok() {
+        should.fail("Expected closure to panic, but it returned normally");
+    }
+}
+
+/// Fails unless calling `f` panics with a message containing `expected`.
+#[track_caller]
+fn expect_panic_with_message_containing<T: ?Sized, R>(
+    should: &Should<'_, T>,
+    f: impl FnOnce() -> R,
+    expected: &str,
+) {
+    match catch_panic(f) {
+        Ok(_) => should.fail(format!(
+            "Expected closure to panic with a message containing {:?}, but it returned normally",
+            expected
+        )),
+        Err(payload) => {
+            let matches =
+                panic_message(payload.as_ref()).is_some_and(|message| message.contains(expected));
+
+            should.check(matches, || {
+                format!(
+                    "Expected closure to panic with a message containing {:?}, but it panicked with {}",
+                    expected,
+                    describe_panic(payload.as_ref())
+                )
+            });
+        }
+    }
+}
+
+/// Fails unless calling `f` panics with a payload of type `P`, and returns a
+/// [`ShouldOwned`] for the payload.
+#[track_caller]
+fn expect_panic_with_payload<'a, T: ?Sized, R, P: Any>(
+    should: &Should<'_, T>,
+    f: impl FnOnce() -> R,
+) -> ShouldOwned<'a, P> {
+    match catch_panic(f) {
+        Ok(_) => should.fail(format!(
+            "Expected closure to panic with a payload of type {}, but it returned normally",
+            type_name::<P>()
+        )),
+        Err(payload) => match payload.downcast::<P>() {
+            Ok(payload) => {
+                let owned = (*payload).into_should();
+
+                match should.path() {
+                    Some(path) => owned.named(path),
+                    None => owned,
+                }
+            }
+            Err(payload) => should.fail(format!(
+                "Expected closure to panic with a payload of type {}, but it panicked with {}",
+                type_name::<P>(),
+                describe_panic(payload.as_ref())
+            )),
+        },
+    }
+}
+
+/// Fails if calling `f` panics, and returns its value otherwise.
+#[track_caller]
+fn expect_no_panic<T: ?Sized, R>(should: &Should<'_, T>, f: impl FnOnce() -> R) -> R {
+    match catch_panic(f) {
+        Ok(value) => value,
+        Err(payload) => should.fail(format!(
+            "Expected closure not to panic, but it panicked with {}",
+            describe_panic(payload.as_ref())
+        )),
+    }
+}
+
+impl<'a, F, R> Should<'a, F>
+where
+    F: Fn() -> R,
+{
+    /// Asserts that calling the closure panics.
+    ///
+    /// The default panic output is suppressed while the closure runs. For
+    /// closures that are only `FnOnce`, use [`ShouldOwned::panic`].
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// (|| panic!("boom")).should().panic();
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the closure returns.
+    #[track_caller]
+    pub fn panic(&self) {
+        expect_panic(self, self.actual());
+    }
+
+    /// Asserts that calling the closure panics with a message containing `expected`.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// (|| panic!("index out of bounds: 3")).should().panic_with_message_containing("out of bounds");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the closure returns, or panics with a different message.
+    #[track_caller]
+    pub fn panic_with_message_containing(&self, expected: &str) {
+        expect_panic_with_message_containing(self, self.actual(), expected);
+    }
+
+    /// Asserts that calling the closure panics with a payload of type `P`, and
+    /// returns a [`ShouldOwned`] for the payload.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct MyErr(u32);
+    ///
+    /// (|| std::panic::panic_any(MyErr(7)))
+    ///     .should()
+    ///     .panic_with_payload::<MyErr>()
+    ///     .eq(&MyErr(7));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the closure returns, or panics with a payload of another type.
+    #[track_caller]
+    pub fn panic_with_payload<P: Any>(&self) -> ShouldOwned<'a, P> {
+        expect_panic_with_payload(self, self.actual())
+    }
+
+    /// Asserts that calling the closure does not panic, and returns its value.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// let value = (|| 40 + 2).should().not_panic();
+    /// value.should().eq(&42);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the closure panics, printing the panic message.
+    #[track_caller]
+    pub fn not_panic(&self) -> R
+    where
+        R: Debug,
+    {
+        expect_no_panic(self, self.actual())
+    }
+}
+
+impl<'a, F, R> ShouldOwned<'a, F>
+where
+    F: FnOnce() -> R,
+{
+    /// Asserts that calling the closure panics, consuming it.
+    ///
+    /// See [`Should::panic`].
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// let name = String::from("Ada");
+    /// (move || -> String { panic!("{} left", name) }).into_should().panic();
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the closure returns.
+    #[track_caller]
+    pub fn panic(self) {
+        let (f, path) = self.into_parts();
+        expect_panic(&Should::new(&(), path), f);
+    }
+
+    /// Asserts that calling the closure panics with a message containing
+    /// `expected`, consuming it.
+    ///
+    /// See [`Should::panic_with_message_containing`].
+    ///
+    /// # Panics
+    /// Panics if the closure returns, or panics with a different message.
+    #[track_caller]
+    pub fn panic_with_message_containing(self, expected: &str) {
+        let (f, path) = self.into_parts();
+        expect_panic_with_message_containing(&Should::new(&(), path), f, expected);
+    }
+
+    /// Asserts that calling the closure panics with a payload of type `P`,
+    /// consuming it, and returns a [`ShouldOwned`] for the payload.
+    ///
+    /// See [`Should::panic_with_payload`].
+    ///
+    /// # Panics
+    /// Panics if the closure returns, or panics with a payload of another type.
+    #[track_caller]
+    pub fn panic_with_payload<P: Any>(self) -> ShouldOwned<'a, P> {
+        let (f, path) = self.into_parts();
+        expect_panic_with_payload(&Should::new(&(), path), f)
+    }
+
+    /// Asserts that calling the closure does not panic, consuming it, and
+    /// returns its value.
+    ///
+    /// See [`Should::not_panic`].
+    ///
+    /// # Panics
+    /// Panics if the closure panics, printing the panic message.
+    #[track_caller]
+    pub fn not_panic(self) -> R
+    where
+        R: Debug,
+    {
+        let (f, path) = self.into_parts();
+        expect_no_panic(&Should::new(&(), path), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Shouldable;
+    use std::panic::panic_any;
+
+    #[derive(Debug, PartialEq)]
+    struct MyErr {
+        code: u32,
+    }
+
+    #[test]
+    fn panic_success_should_not_panic() {
+        (|| panic!("boom")).should().panic();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected closure to panic, but it returned normally")]
+    fn panic_fail_should_panic() {
+        (|| 42).should().panic();
+    }
+
+    #[test]
+    fn panic_with_message_containing_success_should_not_panic() {
+        let values: Vec<i32> = vec![];
+        (|| values[3])
+            .should()
+            .panic_with_message_containing("index out of bounds");
+    }
+
+    #[test]
+    fn panic_with_message_containing_formatted_should_not_panic() {
+        let id = 7;
+        (|| panic!("user {} not found", id))
+            .should()
+            .panic_with_message_containing("user 7");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected closure to panic with a message containing \"timeout\", but it panicked with \"boom\""
+    )]
+    fn panic_with_message_containing_wrong_message_should_panic() {
+        (|| panic!("boom"))
+            .should()
+            .panic_with_message_containing("timeout");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected closure to panic with a message containing \"boom\", but it returned normally"
+    )]
+    fn panic_with_message_containing_no_panic_should_panic() {
+        (|| ()).should().panic_with_message_containing("boom");
+    }
+
+    #[test]
+    fn panic_with_payload_success_should_not_panic() {
+        (|| panic_any(MyErr { code: 7 }))
+            .should()
+            .panic_with_payload::<MyErr>()
+            .field("code", |e| &e.code)
+            .eq(&7);
+    }
+
+    #[test]
+    #[should_panic(expected = "but it panicked with \"boom\"")]
+    fn panic_with_payload_wrong_type_should_panic() {
+        (|| panic!("boom")).should().panic_with_payload::<MyErr>();
+    }
+
+    #[test]
+    fn not_panic_success_should_return_value() {
+        let value = (|| 40 + 2).should().not_panic();
+        value.should().eq(42);
+    }
+
+    #[test]
+    fn panic_without_debug_return_value_should_not_panic() {
+        struct Opaque;
+
+        (|| -> Opaque { panic!("boom") }).should().panic();
+    }
+
+    #[test]
+    fn into_should_panic_fn_once_success_should_not_panic() {
+        let values = vec![1, 2, 3];
+        (move || {
+            drop(values);
+            panic!("dropped")
+        })
+        .into_should()
+        .panic_with_message_containing("dropped");
+    }
+
+    #[test]
+    #[should_panic(expected = "consume: Expected closure to panic, but it returned normally")]
+    fn into_should_panic_fn_once_fail_should_include_path() {
+        let values = vec![1, 2, 3];
+        (move || drop(values))
+            .into_should()
+            .named("consume")
+            .panic();
+    }
+
+    #[test]
+    fn into_should_panic_with_payload_fn_once_should_return_payload() {
+        let err = MyErr { code: 7 };
+        (move || panic_any(err))
+            .into_should()
+            .panic_with_payload::<MyErr>()
+            .eq(&MyErr { code: 7 });
+    }
+
+    #[test]
+    fn into_should_not_panic_fn_once_should_return_value() {
+        let name = String::from("Ada");
+        (move || name).into_should().not_panic().should().eq("Ada");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected closure not to panic, but it panicked with \"boom\"")]
+    fn not_panic_fail_should_panic() {
+        (|| -> i32 { panic!("boom") }).should().not_panic();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "parse: Expected closure not to panic, but it panicked with a non-string payload"
+    )]
+    fn not_panic_should_include_path() {
+        (|| -> i32 { panic_any(MyErr { code: 1 }) })
+            .should()
+            .named("parse")
+            .not_panic();
+    }
+}
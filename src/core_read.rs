@@ -0,0 +1,111 @@
+use crate::ShouldMut;
+use std::io::Read;
+
+impl<'a, R> ShouldMut<'a, R>
+where
+    R: Read + ?Sized,
+{
+    /// Asserts that reading the reader to its end produces the expected text.
+    ///
+    /// Consumes the reader, so it is only available on [`ShouldMut`].
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    /// use std::io::Cursor;
+    ///
+    /// Cursor::new("Hello").into_should().read_text("Hello");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if reading fails, the data is not valid UTF-8 or the text differs.
+    #[track_caller]
+    pub fn read_text(&mut self, expected: &str) {
+        let mut actual = String::new();
+        let result = self.actual_mut().read_to_string(&mut actual);
+
+        if let Err(error) = result {
+            self.fail(format!(
+                "Expected reader to produce {:?}, but reading failed: {}",
+                expected, error
+            ));
+        }
+
+        self.check(actual == expected, || {
+            format!(
+                "Expected reader to produce {:?}, but read {:?}",
+                expected, actual
+            )
+        });
+    }
+
+    /// Asserts that reading the reader to its end produces the expected bytes.
+    ///
+    /// Consumes the reader, so it is only available on [`ShouldMut`].
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// let mut reader: &[u8] = b"abc";
+    /// reader.should_mut().read_bytes(b"abc");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if reading fails or the bytes differ.
+    #[track_caller]
+    pub fn read_bytes(&mut self, expected: &[u8]) {
+        let mut actual = Vec::new();
+        let result = self.actual_mut().read_to_end(&mut actual);
+
+        if let Err(error) = result {
+            self.fail(format!(
+                "Expected reader to produce {:?}, but reading failed: {}",
+                expected, error
+            ));
+        }
+
+        self.check(actual == expected, || {
+            format!(
+                "Expected reader to produce {:?}, but read {:?}",
+                expected, actual
+            )
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Shouldable;
+    use std::io::Cursor;
+
+    #[test]
+    fn read_text_success_should_not_panic() {
+        Cursor::new("Hello").into_should().read_text("Hello");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected reader to produce \"World\", but read \"Hello\"")]
+    fn read_text_fail_should_panic() {
+        Cursor::new("Hello").into_should().read_text("World");
+    }
+
+    #[test]
+    #[should_panic(expected = "reading failed: stream did not contain valid UTF-8")]
+    fn read_text_invalid_utf8_should_panic() {
+        Cursor::new(vec![0xff, 0xfe]).into_should().read_text("");
+    }
+
+    #[test]
+    fn read_bytes_success_should_not_panic() {
+        let mut reader: &[u8] = b"abc";
+        reader.should_mut().read_bytes(b"abc");
+        reader.should().eq(b"");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected reader to produce [97], but read [97, 98, 99]")]
+    fn read_bytes_fail_should_panic() {
+        Cursor::new(b"abc").into_should().read_bytes(b"a");
+    }
+}
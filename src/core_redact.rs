@@ -0,0 +1,702 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+
+thread_local! {
+    /// Redactions bound to the current thread, outermost first.
+    static ACTIVE: RefCell<Vec<Redactions>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A segment of a field path, such as `users`, `[2]` or a wildcard.
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Field(String),
+    Index(usize),
+    Any,
+}
+
+/// A redaction rule.
+#[derive(Debug, Clone)]
+enum Rule {
+    Field {
+        selector: Vec<Segment>,
+        replacement: String,
+    },
+    #[cfg(feature = "regex")]
+    Regex {
+        pattern: regex::Regex,
+        replacement: String,
+    },
+}
+
+impl Rule {
+    /// Returns the selector and replacement of a field rule.
+    fn field(&self) -> Option<(&[Segment], &str)> {
+        match self {
+            Rule::Field {
+                selector,
+                replacement,
+            } => Some((selector, replacement)),
+            #[cfg(feature = "regex")]
+            Rule::Regex { .. } => None,
+        }
+    }
+}
+
+/// Rules that hide nondeterministic values, such as IDs, timestamps or temp
+/// paths, from snapshots and `eq` diffs.
+///
+/// Redactions apply to the text that is compared or diffed, the `{:#?}` or
+/// JSON output of a value, while they are bound to the current thread with
+/// [`Redactions::bind`].
+///
+/// # Examples
+/// ```
+/// use shoulds::{Redactions, Shouldable};
+///
+/// #[derive(Debug)]
+/// struct User {
+///     id: u64,
+///     name: &'static str,
+/// }
+///
+/// Redactions::new().field(".id", "[id]").bind(|| {
+///     User { id: 8317, name: "Ada" }.should().match_inline_snapshot(r#"
+///         User {
+///             id: [id],
+///             name: "Ada",
+///         }
+///     "#);
+/// });
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Redactions {
+    rules: Vec<Rule>,
+    addresses: bool,
+    sort_maps: bool,
+}
+
+impl Redactions {
+    /// Creates an empty set of redactions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the value at `selector` with `replacement`.
+    ///
+    /// Selectors are field paths like `.created_at` or `.user.id`. `[2]`
+    /// selects a sequence element, and `[]` or `*` match any element or field,
+    /// e.g. `.users[].id`. Map entries are selected by their key.
+    ///
+    /// # Panics
+    /// Panics if the selector is malformed.
+    pub fn field(mut self, selector: &str, replacement: &str) -> Self {
+        self.rules.push(Rule::Field {
+            selector: parse_selector(selector),
+            replacement: replacement.to_string(),
+        });
+        self
+    }
+
+    /// Replaces hexadecimal values such as pointer addresses (`0x7ffd5e8c1a30`)
+    /// outside string literals with `[address]`.
+    ///
+    /// This also hides other values printed in hex, so it is off unless
+    /// requested.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::{Redactions, Shouldable};
+    ///
+    /// let value = 42;
+    /// let pointer = &value as *const i32;
+    ///
+    /// Redactions::new()
+    ///     .addresses()
+    ///     .bind(|| pointer.should().match_inline_snapshot("[address]"));
+    /// ```
+    pub fn addresses(mut self) -> Self {
+        self.addresses = true;
+        self
+    }
+
+    /// Sorts the entries of every map or set, so that `HashMap` iteration
+    /// order doesn't cause churn.
+    ///
+    /// Entries with numeric keys come first in numeric order, followed by the
+    /// rest in text order. This also sorts ordered maps such as `BTreeMap`, so
+    /// their order is no longer checked while bound.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::{Redactions, Shouldable};
+    /// use std::collections::HashMap;
+    ///
+    /// let ports: HashMap<&str, u16> = [("https", 443), ("http", 80)].into_iter().collect();
+    ///
+    /// Redactions::new().sort_maps().bind(|| {
+    ///     ports.should().match_inline_snapshot(r#"
+    ///         {
+    ///             "http": 80,
+    ///             "https": 443,
+    ///         }
+    ///     "#);
+    /// });
+    /// ```
+    pub fn sort_maps(mut self) -> Self {
+        self.sort_maps = true;
+        self
+    }
+
+    /// Replaces every match of the regular expression `pattern` with
+    /// `replacement`, which may refer to capture groups like `$1`.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::{Redactions, Shouldable};
+    ///
+    /// let path = "/tmp/.tmpA1b2C3/out.txt";
+    ///
+    /// Redactions::new()
+    ///     .regex(r"\.tmp\w+", "[tmp]")
+    ///     .bind(|| path.should().match_inline_snapshot(r#""/tmp/[tmp]/out.txt""#));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `pattern` is not a valid regular expression.
+    #[cfg(feature = "regex")]
+    pub fn regex(mut self, pattern: &str, replacement: &str) -> Self {
+        let pattern = regex::Regex::new(pattern)
+            .unwrap_or_else(|error| panic!("Invalid redaction pattern {:?}: {}", pattern, error));
+
+        self.rules.push(Rule::Regex {
+            pattern,
+            replacement: replacement.to_string(),
+        });
+        self
+    }
+
+    /// Runs `f` with these redactions applied to every snapshot and `eq` diff
+    /// on the current thread, in addition to any redactions already bound.
+    pub fn bind<R>(self, f: impl FnOnce() -> R) -> R {
+        /// Unbinds the redactions again, even if `f` panics.
+        struct Unbind;
+
+        impl Drop for Unbind {
+            fn drop(&mut self) {
+                ACTIVE.with(|active| active.borrow_mut().pop());
+            }
+        }
+
+        ACTIVE.with(|active| active.borrow_mut().push(self));
+        let _unbind = Unbind;
+
+        f()
+    }
+}
+
+/// Parses a selector such as `.users[].id` into path segments.
+fn parse_selector(selector: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut rest = selector.strip_prefix('.').unwrap_or(selector);
+
+    while !rest.is_empty() {
+        if let Some(inner) = rest.strip_prefix('[') {
+            let end = inner
+                .find(']')
+                .unwrap_or_else(|| panic!("Unclosed `[` in selector {:?}", selector));
+
+            segments.push(match inner[..end].trim() {
+                "" | "*" => Segment::Any,
+                index => Segment::Index(index.parse().unwrap_or_else(|_| {
+                    panic!("Invalid index {:?} in selector {:?}", index, selector)
+                })),
+            });
+            rest = &inner[end + 1..];
+        } else {
+            let end = rest.find(['.', '[']).unwrap_or(rest.len());
+
+            segments.push(match &rest[..end] {
+                "*" => Segment::Any,
+                "" => panic!("Empty field name in selector {:?}", selector),
+                name => Segment::Field(name.to_string()),
+            });
+            rest = &rest[end..];
+        }
+
+        rest = rest.strip_prefix('.').unwrap_or(rest);
+    }
+
+    segments
+}
+
+/// A line of pretty-printed output, with the block it opens, if any.
+#[derive(Debug)]
+struct Node {
+    indent: String,
+    text: String,
+    children: Vec<Node>,
+    close: Option<String>,
+}
+
+impl Node {
+    /// Returns the field or map key this line starts with, e.g. `id` for
+    /// `id: 42,` or `a` for `"a": 1,`.
+    fn key(&self) -> Option<(String, usize)> {
+        let text = self.text.as_str();
+
+        if let Some(quoted) = text.strip_prefix('"') {
+            let mut escaped = false;
+
+            for (index, c) in quoted.char_indices() {
+                match c {
+                    _ if escaped => escaped = false,
+                    '\\' => escaped = true,
+                    '"' => {
+                        let after = 1 + index + 1;
+                        return text[after..]
+                            .starts_with(": ")
+                            .then(|| (quoted[..index].to_string(), after + 2));
+                    }
+                    _ => {}
+                }
+            }
+
+            return None;
+        }
+
+        let end = text.find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '#'))?;
+        (end > 0 && text[end..].starts_with(": "))
+            .then(|| (text[..end].trim_start_matches("r#").to_string(), end + 2))
+    }
+
+    /// Returns whether this line opens an anonymous `{` block, i.e. a map or
+    /// set rather than a named struct.
+    fn opens_map(&self) -> bool {
+        let value = match self.key() {
+            Some((_, start)) => &self.text[start..],
+            None => &self.text,
+        };
+
+        value == "{" && self.close.is_some()
+    }
+
+    /// Returns the last line of this node, which carries its trailing comma.
+    fn last_line(&mut self) -> &mut String {
+        match &mut self.close {
+            Some(close) => close,
+            None => &mut self.text,
+        }
+    }
+
+    fn has_comma(&self) -> bool {
+        self.close.as_ref().unwrap_or(&self.text).ends_with(',')
+    }
+
+    fn set_comma(&mut self, comma: bool) {
+        let line = self.last_line();
+
+        match (line.ends_with(','), comma) {
+            (true, false) => {
+                line.pop();
+            }
+            (false, true) => line.push(','),
+            _ => {}
+        }
+    }
+
+    fn render(&self, output: &mut Vec<String>) {
+        output.push(format!("{}{}", self.indent, self.text));
+
+        for child in &self.children {
+            child.render(output);
+        }
+
+        if let Some(close) = &self.close {
+            output.push(format!("{}{}", self.indent, close));
+        }
+    }
+}
+
+/// Parses lines into nodes until the line closing the current block, which
+/// is returned alongside.
+fn parse_block<'t>(lines: &mut impl Iterator<Item = &'t str>) -> (Vec<Node>, Option<String>) {
+    let mut nodes = Vec::new();
+
+    while let Some(line) = lines.next() {
+        let text = line.trim_start();
+        let indent = line[..line.len() - text.len()].to_string();
+
+        if text.starts_with(['}', ']', ')']) {
+            return (nodes, Some(text.to_string()));
+        }
+
+        let mut node = Node {
+            indent,
+            text: text.to_string(),
+            children: Vec::new(),
+            close: None,
+        };
+
+        if text.ends_with(['{', '[', '(']) {
+            let (children, close) = parse_block(lines);
+            node.children = children;
+            node.close = close;
+        }
+
+        nodes.push(node);
+    }
+
+    (nodes, None)
+}
+
+/// Returns whether `selector` matches `path` exactly.
+fn selects(selector: &[Segment], path: &[Segment]) -> bool {
+    selector.len() == path.len()
+        && selector
+            .iter()
+            .zip(path)
+            .all(|(selector, segment)| *selector == Segment::Any || selector == segment)
+}
+
+/// Replaces the values selected by the field rules, tracking each node's path.
+fn redact_fields(nodes: &mut [Node], path: &mut Vec<Segment>, rules: &[(&[Segment], &str)]) {
+    for (index, node) in nodes.iter_mut().enumerate() {
+        let key = node.key();
+        path.push(match &key {
+            Some((name, _)) => Segment::Field(name.clone()),
+            None => Segment::Index(index),
+        });
+
+        match rules.iter().find(|(selector, _)| selects(selector, path)) {
+            Some((_, replacement)) => {
+                let comma = node.has_comma();
+                let prefix = key.map_or("", |(_, start)| &node.text[..start]);
+
+                node.text = format!("{}{}", prefix, replacement);
+                node.children.clear();
+                node.close = None;
+                node.set_comma(comma);
+            }
+            None => redact_fields(&mut node.children, path, rules),
+        }
+
+        path.pop();
+    }
+}
+
+/// Sorts the entries of every map or set, numeric keys first in numeric
+/// order and the rest by their text.
+fn sort_maps(nodes: &mut [Node]) {
+    for node in nodes.iter_mut() {
+        sort_maps(&mut node.children);
+
+        if node.opens_map() && node.children.len() > 1 {
+            let commas: Vec<bool> = node.children.iter().map(Node::has_comma).collect();
+            let mut entries: Vec<(Option<f64>, String, Node)> = node
+                .children
+                .drain(..)
+                .map(|child| {
+                    let mut lines = Vec::new();
+                    child.render(&mut lines);
+                    let text = lines.join("\n").trim_end_matches(',').to_string();
+                    let number = match child.key() {
+                        Some((key, _)) => number(&key),
+                        None => number(&child.text),
+                    };
+
+                    (number, text, child)
+                })
+                .collect();
+
+            entries.sort_by(|(a, a_text, _), (b, b_text, _)| match (a, b) {
+                (Some(a), Some(b)) => a.total_cmp(b).then_with(|| a_text.cmp(b_text)),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => a_text.cmp(b_text),
+            });
+            node.children = entries.into_iter().map(|(_, _, child)| child).collect();
+
+            for (child, comma) in node.children.iter_mut().zip(commas) {
+                child.set_comma(comma);
+            }
+        }
+    }
+}
+
+/// Parses the decimal number a map key or set entry starts with, if any.
+fn number(text: &str) -> Option<f64> {
+    let end = text
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_')))
+        .unwrap_or(text.len());
+
+    text.starts_with(|c: char| c.is_ascii_digit() || c == '-')
+        .then(|| text[..end].parse().ok())
+        .flatten()
+}
+
+/// Replaces hexadecimal pointer addresses outside string literals.
+fn replace_addresses(line: &str) -> String {
+    let mut output = String::with_capacity(line.len());
+    let mut chars = line.char_indices().peekable();
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut previous = ' ';
+
+    while let Some((index, c)) = chars.next() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == '0'
+            && line[index..].starts_with("0x")
+            && line[index + 2..].starts_with(|c: char| c.is_ascii_hexdigit())
+            && !(previous.is_alphanumeric() || previous == '_')
+        {
+            chars.next();
+            while chars.next_if(|(_, c)| c.is_ascii_hexdigit()).is_some() {}
+
+            output.push_str("[address]");
+            previous = ']';
+            continue;
+        }
+
+        output.push(c);
+        previous = c;
+    }
+
+    output
+}
+
+/// Applies normalization and the redactions bound to the current thread to
+/// pretty-printed output.
+pub(crate) fn redact(text: &str) -> String {
+    ACTIVE.with(|active| {
+        let active = active.borrow();
+        let rules: Vec<&Rule> = active.iter().flat_map(|r| &r.rules).collect();
+        let fields: Vec<(&[Segment], &str)> =
+            rules.iter().filter_map(|rule| rule.field()).collect();
+        let addresses = active.iter().any(|redactions| redactions.addresses);
+        let sorted = active.iter().any(|redactions| redactions.sort_maps);
+
+        let (mut nodes, _) = parse_block(&mut text.lines());
+
+        for node in nodes.iter_mut() {
+            redact_fields(&mut node.children, &mut Vec::new(), &fields);
+        }
+        if sorted {
+            sort_maps(&mut nodes);
+        }
+
+        let mut lines = Vec::new();
+        for node in &nodes {
+            node.render(&mut lines);
+        }
+
+        if addresses {
+            lines = lines.iter().map(|line| replace_addresses(line)).collect();
+        }
+        let text = lines.join("\n");
+
+        #[cfg(feature = "regex")]
+        let text = rules.iter().fold(text, |text, rule| match rule {
+            Rule::Regex {
+                pattern,
+                replacement,
+            } => pattern
+                .replace_all(&text, replacement.as_str())
+                .into_owned(),
+            _ => text,
+        });
+
+        text
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{redact, Redactions};
+    use crate::Shouldable;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Order {
+        id: u64,
+        items: Vec<Item>,
+        tags: HashMap<&'static str, u32>,
+    }
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Item {
+        sku: &'static str,
+        created_at: u64,
+    }
+
+    fn order() -> Order {
+        Order {
+            id: 9_813,
+            items: vec![
+                Item {
+                    sku: "A-1",
+                    created_at: 1_714_564_800,
+                },
+                Item {
+                    sku: "B-2",
+                    created_at: 1_714_564_801,
+                },
+            ],
+            tags: [("zeta", 1), ("alpha", 2), ("mid", 3)]
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn redact_should_sort_map_entries() {
+        Redactions::new()
+            .sort_maps()
+            .bind(|| redact(&format!("{:#?}", order().tags)))
+            .should()
+            .eq("{\n    \"alpha\": 2,\n    \"mid\": 3,\n    \"zeta\": 1,\n}");
+    }
+
+    #[test]
+    fn redact_should_sort_numeric_keys_numerically() {
+        let ranks: BTreeMap<i32, &str> = [(10, "b"), (-1, "z"), (9, "a")].into_iter().collect();
+
+        Redactions::new()
+            .sort_maps()
+            .bind(|| redact(&format!("{:#?}", ranks)))
+            .should()
+            .eq("{\n    -1: \"z\",\n    9: \"a\",\n    10: \"b\",\n}");
+    }
+
+    #[test]
+    fn redact_should_keep_map_order_by_default() {
+        redact("{\n    \"zeta\": 1,\n    \"alpha\": 2,\n}")
+            .should()
+            .eq("{\n    \"zeta\": 1,\n    \"alpha\": 2,\n}");
+    }
+
+    #[test]
+    fn redact_should_replace_selected_fields() {
+        let text = Redactions::new()
+            .field(".id", "[id]")
+            .field(".items[].created_at", "[timestamp]")
+            .field(".tags.mid", "[tag]")
+            .sort_maps()
+            .bind(|| redact(&format!("{:#?}", order())));
+
+        text.should().eq(r#"Order {
+    id: [id],
+    items: [
+        Item {
+            sku: "A-1",
+            created_at: [timestamp],
+        },
+        Item {
+            sku: "B-2",
+            created_at: [timestamp],
+        },
+    ],
+    tags: {
+        "alpha": 2,
+        "mid": [tag],
+        "zeta": 1,
+    },
+}"#);
+    }
+
+    #[test]
+    fn redact_should_replace_whole_blocks_and_indices() {
+        let text = Redactions::new()
+            .field(".items[1]", "[item]")
+            .field(".tags", "[tags]")
+            .bind(|| redact(&format!("{:#?}", order())));
+
+        text.should().eq(r#"Order {
+    id: 9813,
+    items: [
+        Item {
+            sku: "A-1",
+            created_at: 1714564800,
+        },
+        [item],
+    ],
+    tags: [tags],
+}"#);
+    }
+
+    #[test]
+    fn redact_should_keep_json_commas_when_sorting() {
+        Redactions::new()
+            .sort_maps()
+            .bind(|| redact("{\n  \"b\": [\n    1\n  ],\n  \"a\": 2\n}"))
+            .should()
+            .eq("{\n  \"a\": 2,\n  \"b\": [\n    1\n  ]\n}");
+    }
+
+    #[test]
+    fn redact_should_replace_pointer_addresses_outside_strings() {
+        Redactions::new()
+            .addresses()
+            .bind(|| redact("Node {\n    next: 0x7ffd5e8c1a30,\n    label: \"0xff\",\n}"))
+            .should()
+            .eq("Node {\n    next: [address],\n    label: \"0xff\",\n}");
+    }
+
+    #[test]
+    fn redact_should_keep_hex_values_by_default() {
+        redact("Reg(\n    0xbeef,\n)")
+            .should()
+            .eq("Reg(\n    0xbeef,\n)");
+    }
+
+    #[test]
+    #[should_panic(expected = "- Reg(0xbeef)\n+ Reg(0xcafe)")]
+    fn match_inline_snapshot_should_compare_hex_values() {
+        struct Reg(u32);
+
+        impl std::fmt::Debug for Reg {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "Reg({:#x})", self.0)
+            }
+        }
+
+        Reg(0xcafe).should().match_inline_snapshot("Reg(0xbeef)");
+    }
+
+    #[test]
+    fn bind_should_unbind_after_the_closure() {
+        Redactions::new().field(".id", "[id]").bind(|| ());
+
+        redact("User {\n    id: 1,\n}")
+            .should()
+            .eq("User {\n    id: 1,\n}");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unclosed `[` in selector \".items[\"")]
+    fn field_malformed_selector_should_panic() {
+        let _ = Redactions::new().field(".items[", "x");
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn redact_should_apply_regex_rules() {
+        Redactions::new()
+            .regex(
+                r"[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+                "[uuid]",
+            )
+            .bind(|| redact("\"67e55044-10b1-426f-9247-bb680e5fe0c8\""))
+            .should()
+            .eq("\"[uuid]\"");
+    }
+}
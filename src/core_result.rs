@@ -1,3 +1,4 @@
+use crate::matchers::{self, Matcher};
 use crate::Should;
 use std::fmt::Debug;
 
@@ -17,13 +18,23 @@ where
     /// ```
     ///
     /// # Panics
-    /// Panics if the Result is an Err.
+    /// Panics if the Result is an Err. Multi-line error reports, such as those
+    /// of `anyhow` and `eyre`, are printed on their own lines.
     pub fn be_ok(&self) {
-        assert!(
-            self.actual().is_ok(),
-            "Expected Result to be Ok, but got Err({:?})",
-            self.actual().as_ref().err()
-        )
+        self.check(matchers::ok().check(self.actual()), || {
+            let report = self
+                .actual()
+                .as_ref()
+                .err()
+                .map(|error| format!("{:?}", error))
+                .unwrap_or_default();
+
+            if report.contains('\n') {
+                format!("Expected Result to be Ok, but got Err:\n{}", report)
+            } else {
+                format!("Expected Result to be Ok, but got Err({})", report)
+            }
+        })
     }
 
     /// Asserts that the Result is Err.
@@ -39,11 +50,12 @@ where
     /// # Panics
     /// Panics if the Result is Ok.
     pub fn be_err(&self) {
-        assert!(
-            self.actual().is_err(),
-            "Expected Result to be Err, but got Ok({:?})",
-            self.actual().as_ref().ok()
-        )
+        self.check(matchers::err().check(self.actual()), || {
+            format!(
+                "Expected Result to be Err, but got Ok({:?})",
+                self.actual().as_ref().ok()
+            )
+        })
     }
 }
 
@@ -64,6 +76,13 @@ mod tests {
         result.should().be_ok();
     }
 
+    #[test]
+    #[should_panic(expected = "Expected Result to be Ok, but got Err(\"error\")")]
+    fn be_ok_fail_should_print_error() {
+        let result: Result<i32, &str> = Err("error");
+        result.should().be_ok();
+    }
+
     #[test]
     fn be_err_success_should_not_panic() {
         let result: Result<i32, &str> = Err("error");
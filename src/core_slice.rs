@@ -0,0 +1,113 @@
+use crate::Should;
+use std::fmt::Debug;
+
+impl<'a, T> Should<'a, [T]>
+where
+    T: Debug,
+{
+    /// Asserts that the slice is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// let value: Vec<i32> = vec![];
+    /// value.should().as_deref().be_empty();
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the slice is not empty.
+    pub fn be_empty(&self) {
+        self.check(self.actual().is_empty(), || {
+            format!("Expected {:?} to be empty", self.actual())
+        });
+    }
+
+    /// Asserts that the slice has the expected number of items.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// let value = [1, 2, 3];
+    /// value[..].should().have_len(3);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the slice has a different number of items.
+    pub fn have_len(&self, expected: usize) {
+        self.check(self.actual().len() == expected, || {
+            format!(
+                "Expected {:?} to have length {}, but got {}",
+                self.actual(),
+                expected,
+                self.actual().len()
+            )
+        });
+    }
+
+    /// Asserts that the slice contains the expected item.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// let value = vec![1, 2, 3];
+    /// value.should().as_deref().contain(&2);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if no item equals the expected one.
+    pub fn contain(&self, expected: &T)
+    where
+        T: PartialEq,
+    {
+        self.check(self.actual().contains(expected), || {
+            format!("Expected {:?} to contain {:?}", self.actual(), expected)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Shouldable;
+
+    #[test]
+    fn be_empty_success_should_not_panic() {
+        let value: Vec<i32> = vec![];
+        value.should().as_deref().be_empty();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected [1] to be empty")]
+    fn be_empty_fail_should_panic() {
+        let value = vec![1];
+        value.should().as_deref().be_empty();
+    }
+
+    #[test]
+    fn have_len_success_should_not_panic() {
+        let value = [1, 2, 3];
+        value[..].should().have_len(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected [1, 2, 3] to have length 2, but got 3")]
+    fn have_len_fail_should_panic() {
+        let value = [1, 2, 3];
+        value[..].should().have_len(2);
+    }
+
+    #[test]
+    fn contain_success_should_not_panic() {
+        let value = vec!["a", "b"];
+        value.should().as_deref().contain(&"b");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected [\"a\", \"b\"] to contain \"c\"")]
+    fn contain_fail_should_panic() {
+        let value = vec!["a", "b"];
+        value.should().as_deref().contain(&"c");
+    }
+}
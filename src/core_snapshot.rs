@@ -0,0 +1,345 @@
+use crate::core_redact::redact;
+use crate::core_text_diff::line_diff;
+use crate::Should;
+use std::env;
+use std::fmt::Debug;
+use std::fs;
+use std::io;
+use std::panic::Location;
+use std::path::{Path, PathBuf};
+
+/// Environment variable that accepts changed snapshots instead of failing.
+pub(crate) const UPDATE_VAR: &str = "SHOULDS_UPDATE";
+
+/// Returns whether snapshots should be written instead of compared, i.e.
+/// whether `SHOULDS_UPDATE` is set to `1`, `true` or `yes`.
+pub(crate) fn update_mode() -> bool {
+    env::var(UPDATE_VAR)
+        .is_ok_and(|value| matches!(value.to_ascii_lowercase().as_str(), "1" | "true" | "yes"))
+}
+
+/// Resolves a source path as reported by [`Location::file`], which is
+/// relative to the workspace root, while tests run from the package root.
+pub(crate) fn resolve_source(file: &str) -> PathBuf {
+    let root = env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .or_else(|| env::current_dir().ok())
+        .unwrap_or_default();
+
+    root.ancestors()
+        .map(|dir| dir.join(file))
+        .find(|path| path.exists())
+        .unwrap_or_else(|| root.join(file))
+}
+
+/// Where the snapshot `name` taken from the source file `file` is stored.
+struct SnapshotFile {
+    path: PathBuf,
+    pending: PathBuf,
+}
+
+impl SnapshotFile {
+    /// Places the snapshot in a `snapshots` directory next to the source file,
+    /// named after the file and the snapshot, e.g. `snapshots/api__user.snap`.
+    fn for_source(file: &Path, name: &str) -> Self {
+        let stem = file
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let name: String = name
+            .chars()
+            .map(|c| match c {
+                'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '.' => c,
+                _ => '_',
+            })
+            .collect();
+
+        let dir = file
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join("snapshots");
+
+        SnapshotFile {
+            path: dir.join(format!("{}__{}.snap", stem, name)),
+            pending: dir.join(format!("{}__{}.snap.new", stem, name)),
+        }
+    }
+
+    /// Reads the stored snapshot, normalizing line endings.
+    fn read(&self) -> Option<String> {
+        let contents = fs::read_to_string(&self.path).ok()?;
+        let contents = contents.replace("\r\n", "\n");
+
+        Some(
+            contents
+                .strip_suffix('\n')
+                .map(String::from)
+                .unwrap_or(contents),
+        )
+    }
+
+    fn write(path: &Path, contents: &str) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(path, format!("{}\n", contents))
+    }
+
+    fn remove_pending(&self) -> io::Result<()> {
+        match fs::remove_file(&self.pending) {
+            Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'a, T: ?Sized> Should<'a, T> {
+    /// Compares `actual` against the snapshot `name` of the source file
+    /// `source`, writing or accepting snapshots as described on
+    /// [`Should::match_snapshot`].
+    #[track_caller]
+    pub(crate) fn check_snapshot(&self, source: &Path, name: &str, actual: &str, update: bool) {
+        let snapshot = SnapshotFile::for_source(source, name);
+        let expected = snapshot.read();
+        let actual = redact(actual);
+        let actual = actual.as_str();
+
+        let result = match &expected {
+            Some(expected) if expected == actual => snapshot.remove_pending(),
+            _ if update => {
+                SnapshotFile::write(&snapshot.path, actual).and_then(|_| snapshot.remove_pending())
+            }
+            _ => SnapshotFile::write(&snapshot.pending, actual),
+        };
+
+        if let Err(error) = result {
+            self.fail(format!(
+                "Could not update snapshot {:?} at {}: {}",
+                name,
+                snapshot.path.display(),
+                error
+            ));
+        }
+
+        match expected {
+            _ if update => {}
+            Some(expected) if expected == actual => {}
+            Some(expected) => self.fail(format!(
+                "Expected value to match snapshot {:?}, but it differed (- snapshot, + actual):\n{}\nWrote the new value to {}; review it, or rerun with {}=1 to accept it",
+                name,
+                line_diff(&expected, actual),
+                snapshot.pending.display(),
+                UPDATE_VAR
+            )),
+            None => self.fail(format!(
+                "Expected value to match snapshot {:?}, but {} does not exist. New snapshot:\n{}\nWrote it to {}; review it, or rerun with {}=1 to accept it",
+                name,
+                snapshot.path.display(),
+                actual,
+                snapshot.pending.display(),
+                UPDATE_VAR
+            )),
+        }
+    }
+}
+
+impl<'a, T> Should<'a, T>
+where
+    T: Debug + ?Sized,
+{
+    /// Asserts that the value's pretty `Debug` output (`{:#?}`) matches the
+    /// stored snapshot `name`.
+    ///
+    /// Snapshots live in a `snapshots` directory next to the calling source
+    /// file, as `<file>__<name>.snap`. When a snapshot is missing or differs,
+    /// the new value is written to a `.snap.new` file next to it for review,
+    /// and the assertion fails with a line diff. Run the tests with
+    /// `SHOULDS_UPDATE=1` to accept the new values instead.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use shoulds::Shouldable;
+    ///
+    /// #[derive(Debug)]
+    /// struct User {
+    ///     id: u32,
+    ///     name: String,
+    /// }
+    ///
+    /// let user = User { id: 1, name: "Ada".to_string() };
+    /// user.should().match_snapshot("user");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the snapshot is missing or differs, and update mode is off.
+    #[track_caller]
+    pub fn match_snapshot(&self, name: &str) {
+        let source = resolve_source(Location::caller().file());
+        let actual = format!("{:#?}", self.actual());
+
+        self.check_snapshot(&source, name, &actual, update_mode());
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a, T> Should<'a, T>
+where
+    T: serde::Serialize + ?Sized,
+{
+    /// Asserts that the value serialized as pretty-printed JSON matches the
+    /// stored snapshot `name`.
+    ///
+    /// Snapshots are stored and updated as described on
+    /// [`Should::match_snapshot`].
+    ///
+    /// # Panics
+    /// Panics if the value cannot be serialized, or if the snapshot is missing
+    /// or differs and update mode is off.
+    #[track_caller]
+    pub fn match_json_snapshot(&self, name: &str) {
+        let source = resolve_source(Location::caller().file());
+        let actual = match serde_json::to_string_pretty(self.actual()) {
+            Ok(json) => json,
+            Err(error) => self.fail(format!(
+                "Expected value to match snapshot {:?}, but it could not be serialized: {}",
+                name, error
+            )),
+        };
+
+        self.check_snapshot(&source, name, &actual, update_mode());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_panic::catch_panic;
+    use crate::test_support::TempDir;
+    use crate::Shouldable;
+    use std::fs;
+    use std::path::PathBuf;
+
+    #[derive(Debug)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+    #[allow(dead_code)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    /// Returns the path of a source file in a fresh temporary directory, which
+    /// is removed when the guard is dropped.
+    fn temp_source() -> (TempDir, PathBuf) {
+        let dir = TempDir::new();
+        let source = dir.join("api.rs");
+        (dir, source)
+    }
+
+    fn snapshot_dir(source: &std::path::Path) -> PathBuf {
+        source.parent().unwrap().join("snapshots")
+    }
+
+    #[test]
+    fn match_snapshot_should_compare_against_stored_snapshot() {
+        Point { x: 1, y: 2 }.should().match_snapshot("point");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn match_json_snapshot_should_compare_against_stored_snapshot() {
+        Point { x: 1, y: 2 }
+            .should()
+            .match_json_snapshot("point_json");
+    }
+
+    #[test]
+    fn check_snapshot_missing_should_write_pending_file() {
+        let (_dir, source) = temp_source();
+
+        let result = catch_panic(|| {
+            "new"
+                .should()
+                .check_snapshot(&source, "greeting", "hello", false);
+        });
+
+        result.is_err().should().be_true();
+        fs::read_to_string(snapshot_dir(&source).join("api__greeting.snap.new"))
+            .unwrap()
+            .should()
+            .eq("hello\n");
+        snapshot_dir(&source)
+            .join("api__greeting.snap")
+            .exists()
+            .should()
+            .be_false();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected value to match snapshot \"greeting\", but it differed (- snapshot, + actual):\n  hello\n- world\n+ there\nWrote the new value to"
+    )]
+    fn check_snapshot_mismatch_should_panic_with_diff() {
+        let (_dir, source) = temp_source();
+        fs::create_dir_all(snapshot_dir(&source)).unwrap();
+        fs::write(
+            snapshot_dir(&source).join("api__greeting.snap"),
+            "hello\nworld\n",
+        )
+        .unwrap();
+
+        "value"
+            .should()
+            .check_snapshot(&source, "greeting", "hello\nthere", false);
+    }
+
+    #[test]
+    fn check_snapshot_update_should_accept_new_value() {
+        let (_dir, source) = temp_source();
+        fs::create_dir_all(snapshot_dir(&source)).unwrap();
+        fs::write(
+            snapshot_dir(&source).join("api__greeting.snap.new"),
+            "stale\n",
+        )
+        .unwrap();
+
+        "value"
+            .should()
+            .check_snapshot(&source, "greeting", "hello", true);
+
+        fs::read_to_string(snapshot_dir(&source).join("api__greeting.snap"))
+            .unwrap()
+            .should()
+            .eq("hello\n");
+        snapshot_dir(&source)
+            .join("api__greeting.snap.new")
+            .exists()
+            .should()
+            .be_false();
+    }
+
+    #[test]
+    fn check_snapshot_should_ignore_line_endings() {
+        let (_dir, source) = temp_source();
+        fs::create_dir_all(snapshot_dir(&source)).unwrap();
+        fs::write(snapshot_dir(&source).join("api__lines.snap"), "a\r\nb\r\n").unwrap();
+
+        "value"
+            .should()
+            .check_snapshot(&source, "lines", "a\nb", false);
+    }
+
+    #[test]
+    fn check_snapshot_should_sanitize_names() {
+        let (_dir, source) = temp_source();
+
+        "value"
+            .should()
+            .check_snapshot(&source, "user/admin view", "x", true);
+
+        snapshot_dir(&source)
+            .join("api__user_admin_view.snap")
+            .exists()
+            .should()
+            .be_true();
+    }
+}
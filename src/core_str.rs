@@ -0,0 +1,126 @@
+use crate::Should;
+
+impl<'a> Should<'a, str> {
+    /// Asserts that the string is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// "".should().be_empty();
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the string is not empty.
+    pub fn be_empty(&self) {
+        self.check(self.actual().is_empty(), || {
+            format!("Expected {:?} to be empty", self.actual())
+        });
+    }
+
+    /// Asserts that the string contains the expected substring.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// "Hello, world!".should().contain("world");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the string does not contain the substring.
+    pub fn contain(&self, expected: &str) {
+        self.check(self.actual().contains(expected), || {
+            format!("Expected {:?} to contain {:?}", self.actual(), expected)
+        });
+    }
+
+    /// Asserts that the string starts with the expected prefix.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// "Hello, world!".should().start_with("Hello");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the string does not start with the prefix.
+    pub fn start_with(&self, expected: &str) {
+        self.check(self.actual().starts_with(expected), || {
+            format!("Expected {:?} to start with {:?}", self.actual(), expected)
+        });
+    }
+
+    /// Asserts that the string ends with the expected suffix.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// "Hello, world!".should().end_with("world!");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the string does not end with the suffix.
+    pub fn end_with(&self, expected: &str) {
+        self.check(self.actual().ends_with(expected), || {
+            format!("Expected {:?} to end with {:?}", self.actual(), expected)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Shouldable;
+
+    #[test]
+    fn be_empty_success_should_not_panic() {
+        "".should().be_empty();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected \"abc\" to be empty")]
+    fn be_empty_fail_should_panic() {
+        "abc".should().be_empty();
+    }
+
+    #[test]
+    fn contain_success_should_not_panic() {
+        "Hello, world!".should().contain("world");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected \"Hello, world!\" to contain \"universe\"")]
+    fn contain_fail_should_panic() {
+        "Hello, world!".should().contain("universe");
+    }
+
+    #[test]
+    fn start_with_success_should_not_panic() {
+        "Hello, world!".should().start_with("Hello");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected \"Hello, world!\" to start with \"world\"")]
+    fn start_with_fail_should_panic() {
+        "Hello, world!".should().start_with("world");
+    }
+
+    #[test]
+    fn end_with_success_should_not_panic() {
+        "Hello, world!".should().end_with("world!");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected \"Hello, world!\" to end with \"Hello\"")]
+    fn end_with_fail_should_panic() {
+        "Hello, world!".should().end_with("Hello");
+    }
+
+    #[test]
+    fn string_via_as_deref_should_not_panic() {
+        let value = "Hello".to_string();
+        value.should().as_deref().contain("ell");
+    }
+}
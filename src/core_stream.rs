@@ -0,0 +1,399 @@
+use crate::{ShouldMut, ShouldOwned, Shouldable};
+use futures_core::Stream;
+use std::fmt::Debug;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+
+/// How long a pending stream may go without waking before assertions other
+/// than `yield_within` give up on it.
+const WAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A waker that unparks the thread waiting on the stream, and records that it
+/// was woken.
+struct ThreadWaker {
+    thread: Thread,
+    woken: AtomicBool,
+}
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.store(true, Ordering::SeqCst);
+        self.thread.unpark();
+    }
+}
+
+/// Stream assertions for [`ShouldMut`], enabled by the `futures` feature.
+///
+/// Streams are driven on the current thread, parking it while the stream is
+/// pending, so no runtime is needed as long as something wakes the stream,
+/// such as a producer on another thread. Assertions other than
+/// [`yield_within`](StreamAssertions::yield_within) wait for as long as the
+/// stream keeps waking, and fail if it stays pending for 5 seconds without
+/// waking its waker.
+///
+/// These are trait methods rather than inherent methods so that they don't
+/// clash with the iterator assertions of the same name.
+///
+/// # Examples
+/// ```
+/// use futures::stream;
+/// use shoulds::{Shouldable, StreamAssertions};
+///
+/// stream::iter(vec![1, 2, 3])
+///     .into_should()
+///     .yield_items(&[1, 2])
+///     .yield_next(&3)
+///     .end_after(0);
+/// ```
+pub trait StreamAssertions<'a> {
+    /// The type of items produced by the stream.
+    type Item;
+
+    /// Asserts that the stream yields the expected items next, in order.
+    ///
+    /// # Panics
+    /// Panics if an item differs, the stream ends early or it stays pending
+    /// without waking, listing the items received so far.
+    fn yield_items<T>(&mut self, expected: &[T]) -> &mut Self
+    where
+        Self::Item: PartialEq<T>,
+        T: Debug;
+
+    /// Asserts that the next item of the stream equals `expected`.
+    ///
+    /// # Panics
+    /// Panics if the next item differs, the stream has ended or it stays
+    /// pending without waking.
+    fn yield_next<T>(&mut self, expected: &T) -> &mut Self
+    where
+        Self::Item: PartialEq<T>,
+        T: Debug + ?Sized;
+
+    /// Asserts that the stream yields exactly `count` more items and then ends.
+    ///
+    /// # Panics
+    /// Panics if the stream yields fewer or more items, or stays pending
+    /// without waking, listing the items received.
+    fn end_after(&mut self, count: usize) -> &mut Self;
+
+    /// Asserts that the stream yields an item within `limit`, and returns a
+    /// [`Should`] for it.
+    ///
+    /// # Panics
+    /// Panics if the stream is still pending after `limit`, or has ended.
+    fn yield_within(&mut self, limit: Duration) -> ShouldOwned<'a, Self::Item>
+    where
+        Self::Item: 'a;
+}
+
+impl<'a, S> ShouldMut<'a, S>
+where
+    S: Stream + Unpin + ?Sized,
+    S::Item: Debug,
+{
+    /// Waits for the next item of the stream, parking the thread while it is
+    /// pending. Returns `None` if `limit` passes first or, without a limit, if
+    /// the stream goes [`WAKE_TIMEOUT`] without waking.
+    #[track_caller]
+    fn next_item(&mut self, limit: Option<Instant>) -> Option<Option<S::Item>> {
+        let state = Arc::new(ThreadWaker {
+            thread: thread::current(),
+            woken: AtomicBool::new(false),
+        });
+        let waker = Waker::from(Arc::clone(&state));
+        let mut context = Context::from_waker(&waker);
+        let stream = self.actual_mut();
+
+        loop {
+            if let Poll::Ready(item) = Pin::new(&mut *stream).poll_next(&mut context) {
+                return Some(item);
+            }
+
+            let deadline = limit.unwrap_or_else(|| Instant::now() + WAKE_TIMEOUT);
+
+            while !state.woken.swap(false, Ordering::SeqCst) {
+                let now = Instant::now();
+
+                if now >= deadline {
+                    return None;
+                }
+
+                thread::park_timeout(deadline - now);
+            }
+        }
+    }
+
+    /// Waits for the next item of the stream, or its end.
+    ///
+    /// # Panics
+    /// Panics if the stream stays pending without waking.
+    #[track_caller]
+    fn wait_next(&mut self) -> Option<S::Item> {
+        match self.next_item(None) {
+            Some(item) => item,
+            None => self.fail(format!(
+                "Expected stream to yield an item or end, but it was pending for {:?} without waking",
+                WAKE_TIMEOUT
+            )),
+        }
+    }
+}
+
+impl<'a, S> StreamAssertions<'a> for ShouldMut<'a, S>
+where
+    S: Stream + Unpin + ?Sized,
+    S::Item: Debug,
+{
+    type Item = S::Item;
+
+    #[track_caller]
+    fn yield_items<T>(&mut self, expected: &[T]) -> &mut Self
+    where
+        S::Item: PartialEq<T>,
+        T: Debug,
+    {
+        let mut received = Vec::new();
+
+        for item in expected {
+            match self.wait_next() {
+                Some(actual) => {
+                    let matches = actual == *item;
+                    received.push(actual);
+
+                    if !matches {
+                        self.fail(format!(
+                            "Expected stream to yield {:?}, but it yielded {:?}",
+                            expected, received
+                        ));
+                    }
+                }
+                None => self.fail(format!(
+                    "Expected stream to yield {:?}, but it ended after {:?}",
+                    expected, received
+                )),
+            }
+        }
+
+        self
+    }
+
+    #[track_caller]
+    fn yield_next<T>(&mut self, expected: &T) -> &mut Self
+    where
+        S::Item: PartialEq<T>,
+        T: Debug + ?Sized,
+    {
+        match self.wait_next() {
+            Some(actual) => self.check(actual == *expected, || {
+                format!(
+                    "Expected stream to yield {:?} next, but it yielded {:?}",
+                    expected, actual
+                )
+            }),
+            None => self.fail(format!(
+                "Expected stream to yield {:?} next, but it had ended",
+                expected
+            )),
+        }
+
+        self
+    }
+
+    #[track_caller]
+    fn end_after(&mut self, count: usize) -> &mut Self {
+        let mut received = Vec::new();
+
+        while let Some(item) = self.wait_next() {
+            received.push(item);
+
+            if received.len() > count {
+                self.fail(format!(
+                    "Expected stream to end after {} item(s), but it yielded at least {}: {:?}",
+                    count,
+                    received.len(),
+                    received
+                ));
+            }
+        }
+
+        self.check(received.len() == count, || {
+            format!(
+                "Expected stream to end after {} item(s), but it ended after {}: {:?}",
+                count,
+                received.len(),
+                received
+            )
+        });
+
+        self
+    }
+
+    #[track_caller]
+    fn yield_within(&mut self, limit: Duration) -> ShouldOwned<'a, S::Item>
+    where
+        S::Item: 'a,
+    {
+        match self.next_item(Some(Instant::now() + limit)) {
+            Some(Some(item)) => {
+                let should = item.into_should();
+
+                match self.path() {
+                    Some(path) => should.named(path),
+                    None => should,
+                }
+            }
+            Some(None) => self.fail(format!(
+                "Expected stream to yield an item within {:?}, but it had ended",
+                limit
+            )),
+            None => self.fail(format!(
+                "Expected stream to yield an item within {:?}, but it was still pending",
+                limit
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Shouldable, StreamAssertions};
+    use futures::channel::mpsc;
+    use futures::stream::{self, StreamExt};
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn yield_items_success_should_not_panic() {
+        stream::iter(vec![1, 2, 3])
+            .into_should()
+            .yield_items(&[1, 2, 3])
+            .end_after(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected stream to yield [1, 2, 3], but it yielded [1, 5]")]
+    fn yield_items_mismatch_should_panic() {
+        stream::iter(vec![1, 5, 3])
+            .into_should()
+            .yield_items(&[1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected stream to yield [1, 2], but it ended after [1]")]
+    fn yield_items_ended_should_panic() {
+        stream::iter(vec![1]).into_should().yield_items(&[1, 2]);
+    }
+
+    #[test]
+    fn yield_items_should_wait_for_other_threads() {
+        let (mut sender, receiver) = mpsc::unbounded();
+
+        thread::spawn(move || {
+            for event in ["started", "finished"] {
+                thread::sleep(Duration::from_millis(5));
+                sender.start_send(event).unwrap();
+            }
+        });
+
+        receiver
+            .into_should()
+            .yield_items(&["started", "finished"])
+            .end_after(0);
+    }
+
+    #[test]
+    fn yield_next_success_should_not_panic() {
+        stream::iter(vec!["a".to_string(), "b".to_string()])
+            .into_should()
+            .yield_next("a")
+            .yield_next("b");
+    }
+
+    #[test]
+    #[should_panic(expected = "events: Expected stream to yield 2 next, but it yielded 1")]
+    fn yield_next_mismatch_should_panic_with_path() {
+        stream::iter(vec![1])
+            .into_should()
+            .named("events")
+            .yield_next(&2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected stream to yield 1 next, but it had ended")]
+    fn yield_next_ended_should_panic() {
+        stream::empty::<i32>().into_should().yield_next(&1);
+    }
+
+    #[test]
+    fn end_after_success_should_not_panic() {
+        stream::iter(0..3)
+            .map(|x| x * 10)
+            .into_should()
+            .end_after(3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected stream to end after 1 item(s), but it yielded at least 2: [0, 1]"
+    )]
+    fn end_after_too_many_should_panic() {
+        stream::iter(0..).into_should().end_after(1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected stream to end after 3 item(s), but it ended after 2: [0, 1]"
+    )]
+    fn end_after_too_few_should_panic() {
+        stream::iter(0..2).into_should().end_after(3);
+    }
+
+    #[test]
+    fn yield_within_success_should_return_item() {
+        let (mut sender, receiver) = mpsc::unbounded();
+
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(5));
+            sender.start_send(42).unwrap();
+        });
+
+        receiver
+            .into_should()
+            .yield_within(Duration::from_secs(5))
+            .eq(42);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected stream to yield an item within 20ms, but it was still pending"
+    )]
+    fn yield_within_pending_should_panic() {
+        stream::pending::<i32>()
+            .into_should()
+            .yield_within(Duration::from_millis(20));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected stream to yield an item within 1s, but it had ended")]
+    fn yield_within_ended_should_panic() {
+        stream::empty::<i32>()
+            .into_should()
+            .yield_within(Duration::from_secs(1));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected stream to yield an item or end, but it was pending for 5s without waking"
+    )]
+    fn yield_next_never_waking_should_panic() {
+        stream::pending::<i32>().into_should().yield_next(&1);
+    }
+}
@@ -0,0 +1,166 @@
+/// Number of unchanged lines shown around each change.
+pub(crate) const CONTEXT: usize = 3;
+
+/// Largest LCS table, in cells, built for the lines that differ. Beyond it,
+/// the differing lines are listed as removed and then added instead.
+pub(crate) const MAX_DIFF_CELLS: usize = 1 << 22;
+
+/// A line of a diff between two texts.
+#[derive(Debug, PartialEq)]
+enum Line<'t> {
+    Same(&'t str),
+    Removed(&'t str),
+    Added(&'t str),
+}
+
+/// Computes the line-by-line edit script from `expected` to `actual`, using
+/// the longest common subsequence of the lines that differ, as long as its
+/// table stays within [`MAX_DIFF_CELLS`].
+fn diff_lines<'t>(expected: &[&'t str], actual: &[&'t str]) -> Vec<Line<'t>> {
+    let prefix = expected
+        .iter()
+        .zip(actual)
+        .take_while(|(e, a)| e == a)
+        .count();
+    let suffix = expected[prefix..]
+        .iter()
+        .rev()
+        .zip(actual[prefix..].iter().rev())
+        .take_while(|(e, a)| e == a)
+        .count();
+
+    let old = &expected[prefix..expected.len() - suffix];
+    let new = &actual[prefix..actual.len() - suffix];
+
+    let mut lines: Vec<Line> = expected[..prefix].iter().map(|l| Line::Same(l)).collect();
+    let same_suffix = expected[expected.len() - suffix..]
+        .iter()
+        .map(|l| Line::Same(l));
+
+    if old.len().saturating_mul(new.len()) > MAX_DIFF_CELLS {
+        lines.extend(old.iter().map(|l| Line::Removed(l)));
+        lines.extend(new.iter().map(|l| Line::Added(l)));
+        lines.extend(same_suffix);
+        return lines;
+    }
+
+    // lengths[i][j] is the LCS length of old[i..] and new[j..].
+    let mut lengths = vec![vec![0u32; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if old[i] == new[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(Line::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lengths[i + 1][j] >= lengths[i][j + 1]) {
+            lines.push(Line::Removed(old[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(new[j]));
+            j += 1;
+        }
+    }
+
+    lines.extend(same_suffix);
+    lines
+}
+
+/// Renders a line diff from `expected` to `actual`, prefixing removed lines
+/// with `-`, added lines with `+`, and collapsing long unchanged runs.
+pub(crate) fn line_diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let lines = diff_lines(&expected, &actual);
+
+    let mut near_change = vec![false; lines.len()];
+    for (index, line) in lines.iter().enumerate() {
+        if !matches!(line, Line::Same(_)) {
+            let end = (index + CONTEXT + 1).min(lines.len());
+            near_change[index.saturating_sub(CONTEXT)..end].fill(true);
+        }
+    }
+
+    let mut output = Vec::new();
+    let mut skipped = false;
+
+    for (index, line) in lines.iter().enumerate() {
+        match line {
+            Line::Same(_) if !near_change[index] => {
+                if !skipped {
+                    output.push("  ...".to_string());
+                    skipped = true;
+                }
+                continue;
+            }
+            Line::Same(text) => output.push(format!("  {}", text)),
+            Line::Removed(text) => output.push(format!("- {}", text)),
+            Line::Added(text) => output.push(format!("+ {}", text)),
+        }
+
+        skipped = false;
+    }
+
+    output.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{line_diff, MAX_DIFF_CELLS};
+    use crate::Shouldable;
+
+    #[test]
+    fn line_diff_should_mark_changed_lines() {
+        line_diff("a\nb\nc", "a\nx\nc")
+            .should()
+            .eq("  a\n- b\n+ x\n  c");
+    }
+
+    #[test]
+    fn line_diff_should_handle_insertions_and_removals() {
+        line_diff("a\nb\nc", "b\nc\nd")
+            .should()
+            .eq("- a\n  b\n  c\n+ d");
+    }
+
+    #[test]
+    fn line_diff_should_collapse_distant_context() {
+        let expected: Vec<String> = (1..=10).map(|n| n.to_string()).collect();
+        let mut actual = expected.clone();
+        actual[9] = "ten".to_string();
+
+        line_diff(&expected.join("\n"), &actual.join("\n"))
+            .should()
+            .eq("  ...\n  7\n  8\n  9\n- 10\n+ ten");
+    }
+
+    #[test]
+    fn line_diff_should_list_large_changes_without_matching() {
+        let side = (MAX_DIFF_CELLS as f64).sqrt() as usize + 1;
+        let expected: Vec<String> = (0..side).map(|n| n.to_string()).collect();
+        let actual: Vec<String> = (1..=side).map(|n| n.to_string()).collect();
+
+        let diff = line_diff(&expected.join("\n"), &actual.join("\n"));
+
+        diff.lines()
+            .filter(|line| line.starts_with('-'))
+            .count()
+            .should()
+            .eq(&side);
+        diff.lines()
+            .filter(|line| line.starts_with('+'))
+            .count()
+            .should()
+            .eq(&side);
+    }
+}
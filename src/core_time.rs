@@ -0,0 +1,573 @@
+use crate::Should;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Formats a duration for failure messages, e.g. `250ms`, `1.5s` or `2m 3s`.
+pub(crate) fn format_duration(duration: Duration) -> String {
+    /// Formats `value` units of `scale` each, with up to three decimals,
+    /// dropping trailing zeros. `value` is a multiple of `scale / 1000`.
+    fn decimal(value: u128, scale: u128, unit: &str) -> String {
+        let whole = value / scale;
+        let thousandths = value % scale / (scale / 1_000);
+
+        let formatted = format!("{}.{:03}", whole, thousandths);
+        let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+        format!("{}{}", trimmed, unit)
+    }
+
+    /// Rounds `nanos` to the nearest multiple of `step`.
+    fn round(nanos: u128, step: u128) -> u128 {
+        (nanos + step / 2) / step * step
+    }
+
+    const MICRO: u128 = 1_000;
+    const MILLI: u128 = 1_000_000;
+    const SECOND: u128 = 1_000_000_000;
+
+    // Rounded to the displayed precision before choosing units, so that e.g.
+    // 999.9999ms shows as 1s rather than 1000ms.
+    let nanos = duration.as_nanos();
+
+    if nanos < MICRO {
+        return format!("{}ns", nanos);
+    }
+    if nanos < MILLI {
+        return decimal(nanos, MICRO, "µs");
+    }
+    if round(nanos, MICRO) < SECOND {
+        return decimal(round(nanos, MICRO), MILLI, "ms");
+    }
+
+    let nanos = round(nanos, MILLI);
+    let secs = nanos / SECOND;
+
+    if secs < 60 {
+        return decimal(nanos, SECOND, "s");
+    }
+
+    let mut parts = Vec::new();
+
+    if secs >= 3600 {
+        parts.push(format!("{}h", secs / 3600));
+    }
+    if secs % 3600 >= 60 {
+        parts.push(format!("{}m", secs % 3600 / 60));
+    }
+    if nanos % (60 * SECOND) != 0 {
+        parts.push(decimal(nanos % (60 * SECOND), SECOND, "s"));
+    }
+
+    parts.join(" ")
+}
+
+/// Formats a signed offset for failure messages, e.g. `12ms later`.
+pub(crate) fn format_offset(later: bool, delta: Duration) -> String {
+    match (later, delta.is_zero()) {
+        (_, true) => "at the same time".to_string(),
+        (true, false) => format!("{} later", format_duration(delta)),
+        (false, false) => format!("{} earlier", format_duration(delta)),
+    }
+}
+
+/// Formats a signed delta for failure messages, e.g. `+1.5s` or `-2m`.
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) fn format_signed_duration(negative: bool, delta: Duration) -> String {
+    match (negative, delta.is_zero()) {
+        (_, true) => format_duration(delta),
+        (true, false) => format!("-{}", format_duration(delta)),
+        (false, false) => format!("+{}", format_duration(delta)),
+    }
+}
+
+/// Formats a system time as an ISO-8601 timestamp in UTC.
+pub(crate) fn format_system_time(time: SystemTime) -> String {
+    let (secs, nanos) = match time.duration_since(UNIX_EPOCH) {
+        Ok(since) => (since.as_secs() as i64, since.subsec_nanos()),
+        Err(error) => {
+            let before = error.duration();
+            match before.subsec_nanos() {
+                0 => (-(before.as_secs() as i64), 0),
+                nanos => (-(before.as_secs() as i64) - 1, 1_000_000_000 - nanos),
+            }
+        }
+    };
+
+    // Converts days since the epoch to a civil date, after Howard Hinnant's
+    // `civil_from_days`.
+    let days = secs.div_euclid(86_400);
+    let seconds_of_day = secs.rem_euclid(86_400);
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    let fraction = match nanos {
+        0 => String::new(),
+        nanos => format!(".{:09}", nanos).trim_end_matches('0').to_string(),
+    };
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}Z",
+        year,
+        month,
+        day,
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60,
+        seconds_of_day % 60,
+        fraction
+    )
+}
+
+/// Returns whether `actual` is later than `expected`, and by how much they differ.
+fn instant_offset(actual: Instant, expected: Instant) -> (bool, Duration) {
+    match actual.checked_duration_since(expected) {
+        Some(delta) => (true, delta),
+        None => (false, expected - actual),
+    }
+}
+
+/// Returns whether `actual` is later than `expected`, and by how much they differ.
+fn system_time_offset(actual: SystemTime, expected: SystemTime) -> (bool, Duration) {
+    match actual.duration_since(expected) {
+        Ok(delta) => (true, delta),
+        Err(error) => (false, error.duration()),
+    }
+}
+
+/// A pending tolerance check, created by `be_close_to` and completed with
+/// [`CloseTo::within`].
+pub struct CloseTo<'s, 'a, T> {
+    pub(crate) should: &'s Should<'a, T>,
+    pub(crate) expected: T,
+}
+
+/// A pending tolerance check, created by `be_within` and completed with
+/// `of`.
+pub struct Within<'s, 'a, T> {
+    pub(crate) should: &'s Should<'a, T>,
+    pub(crate) tolerance: Duration,
+}
+
+impl<'a> Should<'a, Duration> {
+    /// Starts asserting that the duration is close to `expected`; complete the
+    /// assertion with [`CloseTo::within`].
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    /// use std::time::Duration;
+    ///
+    /// let elapsed = Duration::from_millis(104);
+    /// elapsed
+    ///     .should()
+    ///     .be_close_to(Duration::from_millis(100))
+    ///     .within(Duration::from_millis(10));
+    /// ```
+    pub fn be_close_to(&self, expected: Duration) -> CloseTo<'_, 'a, Duration> {
+        CloseTo {
+            should: self,
+            expected,
+        }
+    }
+}
+
+impl CloseTo<'_, '_, Duration> {
+    /// Asserts that the duration differs from the expected one by at most
+    /// `tolerance`.
+    ///
+    /// # Panics
+    /// Panics if the difference is larger, printing it with its sign.
+    #[track_caller]
+    pub fn within(&self, tolerance: Duration) {
+        let actual = *self.should.actual();
+        let later = actual >= self.expected;
+        let delta = if later {
+            actual - self.expected
+        } else {
+            self.expected - actual
+        };
+
+        self.should.check(delta <= tolerance, || {
+            format!(
+                "Expected {} to be within {} of {}, but it was {} {}",
+                format_duration(actual),
+                format_duration(tolerance),
+                format_duration(self.expected),
+                format_duration(delta),
+                if later { "longer" } else { "shorter" }
+            )
+        });
+    }
+}
+
+impl<'a> Should<'a, Instant> {
+    /// Asserts that the instant is strictly before `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    /// use std::time::Instant;
+    ///
+    /// let start = Instant::now();
+    /// start.should().be_before(Instant::now() + std::time::Duration::from_millis(1));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the instant is at or after `other`, printing the offset.
+    #[track_caller]
+    pub fn be_before(&self, other: Instant) {
+        let (later, delta) = instant_offset(*self.actual(), other);
+
+        self.check(!later, || {
+            format!(
+                "Expected instant to be before the other instant, but it was {}",
+                format_offset(later, delta)
+            )
+        });
+    }
+
+    /// Asserts that the instant is strictly after `other`.
+    ///
+    /// # Panics
+    /// Panics if the instant is at or before `other`, printing the offset.
+    #[track_caller]
+    pub fn be_after(&self, other: Instant) {
+        let (later, delta) = instant_offset(*self.actual(), other);
+
+        self.check(later && !delta.is_zero(), || {
+            format!(
+                "Expected instant to be after the other instant, but it was {}",
+                format_offset(later, delta)
+            )
+        });
+    }
+
+    /// Starts asserting that the instant is at most `tolerance` away from
+    /// another; complete the assertion with `of`.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let deadline = Instant::now() + Duration::from_millis(5);
+    /// deadline
+    ///     .should()
+    ///     .be_within(Duration::from_secs(1))
+    ///     .of(Instant::now());
+    /// ```
+    pub fn be_within(&self, tolerance: Duration) -> Within<'_, 'a, Instant> {
+        Within {
+            should: self,
+            tolerance,
+        }
+    }
+}
+
+impl Within<'_, '_, Instant> {
+    /// Asserts that the instant is at most the tolerance away from `expected`.
+    ///
+    /// # Panics
+    /// Panics if it is further away, printing the offset.
+    #[track_caller]
+    pub fn of(&self, expected: Instant) {
+        let (later, delta) = instant_offset(*self.should.actual(), expected);
+
+        self.should.check(delta <= self.tolerance, || {
+            format!(
+                "Expected instant to be within {} of the other instant, but it was {}",
+                format_duration(self.tolerance),
+                format_offset(later, delta)
+            )
+        });
+    }
+}
+
+impl<'a> Should<'a, SystemTime> {
+    /// Asserts that the time is strictly before `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    /// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    ///
+    /// UNIX_EPOCH.should().be_before(SystemTime::now());
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the time is at or after `other`, printing both as ISO-8601
+    /// timestamps along with the offset.
+    #[track_caller]
+    pub fn be_before(&self, other: SystemTime) {
+        let (later, delta) = system_time_offset(*self.actual(), other);
+
+        self.check(!later, || {
+            format!(
+                "Expected {} to be before {}, but it was {}",
+                format_system_time(*self.actual()),
+                format_system_time(other),
+                format_offset(later, delta)
+            )
+        });
+    }
+
+    /// Asserts that the time is strictly after `other`.
+    ///
+    /// # Panics
+    /// Panics if the time is at or before `other`, printing both as ISO-8601
+    /// timestamps along with the offset.
+    #[track_caller]
+    pub fn be_after(&self, other: SystemTime) {
+        let (later, delta) = system_time_offset(*self.actual(), other);
+
+        self.check(later && !delta.is_zero(), || {
+            format!(
+                "Expected {} to be after {}, but it was {}",
+                format_system_time(*self.actual()),
+                format_system_time(other),
+                format_offset(later, delta)
+            )
+        });
+    }
+
+    /// Starts asserting that the time is at most `tolerance` away from
+    /// another; complete the assertion with `of`.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// let created_at = SystemTime::now();
+    /// created_at
+    ///     .should()
+    ///     .be_within(Duration::from_secs(5))
+    ///     .of(SystemTime::now());
+    /// ```
+    pub fn be_within(&self, tolerance: Duration) -> Within<'_, 'a, SystemTime> {
+        Within {
+            should: self,
+            tolerance,
+        }
+    }
+}
+
+impl Within<'_, '_, SystemTime> {
+    /// Asserts that the time is at most the tolerance away from `expected`.
+    ///
+    /// # Panics
+    /// Panics if it is further away, printing both as ISO-8601 timestamps
+    /// along with the offset.
+    #[track_caller]
+    pub fn of(&self, expected: SystemTime) {
+        let actual = *self.should.actual();
+        let (later, delta) = system_time_offset(actual, expected);
+
+        self.should.check(delta <= self.tolerance, || {
+            format!(
+                "Expected {} to be within {} of {}, but it was {}",
+                format_system_time(actual),
+                format_duration(self.tolerance),
+                format_system_time(expected),
+                format_offset(later, delta)
+            )
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_duration, format_system_time};
+    use crate::Shouldable;
+    use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+    fn may_day_noon() -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1_714_564_800)
+    }
+
+    #[test]
+    fn format_duration_should_pick_readable_units() {
+        format_duration(Duration::ZERO).should().eq("0ns");
+        format_duration(Duration::from_nanos(250))
+            .should()
+            .eq("250ns");
+        format_duration(Duration::from_nanos(12_500))
+            .should()
+            .eq("12.5µs");
+        format_duration(Duration::from_millis(250))
+            .should()
+            .eq("250ms");
+        format_duration(Duration::from_millis(1_500))
+            .should()
+            .eq("1.5s");
+        format_duration(Duration::from_secs(123))
+            .should()
+            .eq("2m 3s");
+        format_duration(Duration::from_secs(3_600))
+            .should()
+            .eq("1h");
+        format_duration(Duration::from_millis(3_725_500))
+            .should()
+            .eq("1h 2m 5.5s");
+        format_duration(Duration::from_nanos(999_999_900))
+            .should()
+            .eq("1s");
+        format_duration(Duration::from_nanos(999_900_000))
+            .should()
+            .eq("999.9ms");
+        format_duration(Duration::from_nanos(119_999_999_999))
+            .should()
+            .eq("2m");
+        format_duration(Duration::from_nanos(59_999_600_000))
+            .should()
+            .eq("1m");
+        format_duration(Duration::from_nanos(3_599_999_999_999))
+            .should()
+            .eq("1h");
+        format_duration(Duration::from_nanos(1_234_567))
+            .should()
+            .eq("1.235ms");
+    }
+
+    #[test]
+    fn format_system_time_should_use_iso_8601() {
+        format_system_time(UNIX_EPOCH)
+            .should()
+            .eq("1970-01-01T00:00:00Z");
+        format_system_time(may_day_noon())
+            .should()
+            .eq("2024-05-01T12:00:00Z");
+        format_system_time(may_day_noon() + Duration::from_millis(250))
+            .should()
+            .eq("2024-05-01T12:00:00.25Z");
+        format_system_time(UNIX_EPOCH - Duration::from_millis(500))
+            .should()
+            .eq("1969-12-31T23:59:59.5Z");
+    }
+
+    #[test]
+    fn duration_be_close_to_success_should_not_panic() {
+        Duration::from_millis(95)
+            .should()
+            .be_close_to(Duration::from_millis(100))
+            .within(Duration::from_millis(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 112ms to be within 10ms of 100ms, but it was 12ms longer")]
+    fn duration_be_close_to_longer_should_panic() {
+        Duration::from_millis(112)
+            .should()
+            .be_close_to(Duration::from_millis(100))
+            .within(Duration::from_millis(10));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "elapsed: Expected 1.5s to be within 100ms of 2s, but it was 500ms shorter"
+    )]
+    fn duration_be_close_to_shorter_should_panic_with_path() {
+        Duration::from_millis(1_500)
+            .should()
+            .named("elapsed")
+            .be_close_to(Duration::from_secs(2))
+            .within(Duration::from_millis(100));
+    }
+
+    #[test]
+    fn instant_be_before_and_after_should_not_panic() {
+        let start = Instant::now();
+        let end = start + Duration::from_millis(5);
+
+        start.should().be_before(end);
+        end.should().be_after(start);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected instant to be before the other instant, but it was 5ms later"
+    )]
+    fn instant_be_before_fail_should_panic() {
+        let start = Instant::now();
+        (start + Duration::from_millis(5)).should().be_before(start);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected instant to be after the other instant, but it was at the same time"
+    )]
+    fn instant_be_after_same_instant_should_panic() {
+        let start = Instant::now();
+        start.should().be_after(start);
+    }
+
+    #[test]
+    fn instant_be_within_success_should_not_panic() {
+        let start = Instant::now();
+        start
+            .should()
+            .be_within(Duration::from_millis(10))
+            .of(start + Duration::from_millis(10));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected instant to be within 10ms of the other instant, but it was 25ms earlier"
+    )]
+    fn instant_be_within_fail_should_panic() {
+        let start = Instant::now();
+        start
+            .should()
+            .be_within(Duration::from_millis(10))
+            .of(start + Duration::from_millis(25));
+    }
+
+    #[test]
+    fn system_time_be_before_success_should_not_panic() {
+        may_day_noon().should().be_before(SystemTime::now());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected 2024-05-01T12:00:01.5Z to be before 2024-05-01T12:00:00Z, but it was 1.5s later"
+    )]
+    fn system_time_be_before_fail_should_panic() {
+        (may_day_noon() + Duration::from_millis(1_500))
+            .should()
+            .be_before(may_day_noon());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected 2024-05-01T11:58:00Z to be after 2024-05-01T12:00:00Z, but it was 2m earlier"
+    )]
+    fn system_time_be_after_fail_should_panic() {
+        (may_day_noon() - Duration::from_secs(120))
+            .should()
+            .be_after(may_day_noon());
+    }
+
+    #[test]
+    fn system_time_be_within_success_should_not_panic() {
+        SystemTime::now()
+            .should()
+            .be_within(Duration::from_secs(5))
+            .of(SystemTime::now());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected 2024-05-01T12:00:03Z to be within 1s of 2024-05-01T12:00:00Z, but it was 3s later"
+    )]
+    fn system_time_be_within_fail_should_panic() {
+        (may_day_noon() + Duration::from_secs(3))
+            .should()
+            .be_within(Duration::from_secs(1))
+            .of(may_day_noon());
+    }
+}
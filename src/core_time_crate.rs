@@ -0,0 +1,295 @@
+use crate::core_time::{format_duration, format_signed_duration, CloseTo};
+use crate::Should;
+use std::time::Duration;
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset, Weekday};
+
+/// Formats a date-time as an ISO-8601 timestamp, using `Z` for UTC.
+///
+/// Years that RFC 3339 cannot represent fall back to the `Debug` output.
+fn format_date_time(time: &OffsetDateTime) -> String {
+    time.format(&Rfc3339)
+        .unwrap_or_else(|_| format!("{:?}", time))
+}
+
+/// Formats a UTC offset as `+hh:mm`, adding seconds only when present.
+fn format_offset(offset: UtcOffset) -> String {
+    let (hours, minutes, seconds) = offset.as_hms();
+    let sign = if offset.is_negative() { '-' } else { '+' };
+
+    match seconds {
+        0 => format!("{}{:02}:{:02}", sign, hours.abs(), minutes.abs()),
+        _ => format!(
+            "{}{:02}:{:02}:{:02}",
+            sign,
+            hours.abs(),
+            minutes.abs(),
+            seconds.abs()
+        ),
+    }
+}
+
+/// Returns the signed difference `actual - expected` for failure messages.
+fn signed_delta(actual: &OffsetDateTime, expected: &OffsetDateTime) -> (bool, Duration) {
+    let delta = *actual - *expected;
+
+    (delta.is_negative(), delta.unsigned_abs())
+}
+
+impl<'a> Should<'a, OffsetDateTime> {
+    /// Asserts that the date-time is strictly before `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    /// use time::{Date, Month};
+    ///
+    /// let created = Date::from_calendar_date(2024, Month::May, 1).unwrap().midnight().assume_utc();
+    /// let updated = Date::from_calendar_date(2024, Month::May, 2).unwrap().midnight().assume_utc();
+    /// created.should().be_before(updated);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the date-time is at or after `other`, printing both as
+    /// ISO-8601 along with the signed difference.
+    #[track_caller]
+    pub fn be_before(&self, other: OffsetDateTime) {
+        self.check(*self.actual() < other, || {
+            let (negative, delta) = signed_delta(self.actual(), &other);
+            format!(
+                "Expected {} to be before {}, but the difference was {}",
+                format_date_time(self.actual()),
+                format_date_time(&other),
+                format_signed_duration(negative, delta)
+            )
+        });
+    }
+
+    /// Asserts that the date-time is strictly after `other`.
+    ///
+    /// # Panics
+    /// Panics if the date-time is at or before `other`, printing both as
+    /// ISO-8601 along with the signed difference.
+    #[track_caller]
+    pub fn be_after(&self, other: OffsetDateTime) {
+        self.check(*self.actual() > other, || {
+            let (negative, delta) = signed_delta(self.actual(), &other);
+            format!(
+                "Expected {} to be after {}, but the difference was {}",
+                format_date_time(self.actual()),
+                format_date_time(&other),
+                format_signed_duration(negative, delta)
+            )
+        });
+    }
+
+    /// Asserts that the date-time falls on the same calendar day as `other`,
+    /// at the date-time's own offset.
+    ///
+    /// # Panics
+    /// Panics if the days differ, printing both dates.
+    #[track_caller]
+    pub fn be_same_day_as(&self, other: OffsetDateTime) {
+        let actual = self.actual().date();
+        let expected = other.to_offset(self.actual().offset()).date();
+
+        self.check(actual == expected, || {
+            format!(
+                "Expected {} to be on the same day as {}, but it was on {} instead of {}",
+                format_date_time(self.actual()),
+                format_date_time(&other),
+                actual,
+                expected
+            )
+        });
+    }
+
+    /// Asserts that the date-time falls on `expected`, at its own offset.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    /// use time::{Date, Month, Weekday};
+    ///
+    /// let release = Date::from_calendar_date(2024, Month::May, 1).unwrap().midnight().assume_utc();
+    /// release.should().be_weekday(Weekday::Wednesday);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the date-time falls on another weekday.
+    #[track_caller]
+    pub fn be_weekday(&self, expected: Weekday) {
+        let actual = self.actual().weekday();
+
+        self.check(actual == expected, || {
+            format!(
+                "Expected {} to be on a {}, but it was on a {}",
+                format_date_time(self.actual()),
+                expected,
+                actual
+            )
+        });
+    }
+
+    /// Asserts that the date-time has the UTC offset `expected`.
+    ///
+    /// # Panics
+    /// Panics if the offset differs, printing both offsets.
+    #[track_caller]
+    pub fn be_in_timezone(&self, expected: UtcOffset) {
+        let actual = self.actual().offset();
+
+        self.check(actual == expected, || {
+            format!(
+                "Expected {} to be in timezone {}, but its offset was {}",
+                format_date_time(self.actual()),
+                format_offset(expected),
+                format_offset(actual)
+            )
+        });
+    }
+
+    /// Starts asserting that the date-time is close to `expected`; complete
+    /// the assertion with [`CloseTo::within`].
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    /// use std::time::Duration;
+    /// use time::{Date, Month};
+    ///
+    /// let received = Date::from_calendar_date(2024, Month::May, 1).unwrap().midnight().assume_utc();
+    /// let sent = received + Duration::from_secs(1);
+    /// sent.should().be_close_to(received).within(Duration::from_secs(2));
+    /// ```
+    pub fn be_close_to(&self, expected: OffsetDateTime) -> CloseTo<'_, 'a, OffsetDateTime> {
+        CloseTo {
+            should: self,
+            expected,
+        }
+    }
+}
+
+impl CloseTo<'_, '_, OffsetDateTime> {
+    /// Asserts that the date-time differs from the expected one by at most
+    /// `tolerance`.
+    ///
+    /// # Panics
+    /// Panics if the difference is larger, printing both as ISO-8601 along
+    /// with the signed difference.
+    #[track_caller]
+    pub fn within(&self, tolerance: Duration) {
+        let (negative, delta) = signed_delta(self.should.actual(), &self.expected);
+
+        self.should.check(delta <= tolerance, || {
+            format!(
+                "Expected {} to be within {} of {}, but the difference was {}",
+                format_date_time(self.should.actual()),
+                format_duration(tolerance),
+                format_date_time(&self.expected),
+                format_signed_duration(negative, delta)
+            )
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Shouldable;
+    use std::time::Duration;
+    use time::{Date, Month, OffsetDateTime, UtcOffset, Weekday};
+
+    fn noon() -> OffsetDateTime {
+        Date::from_calendar_date(2024, Month::May, 1)
+            .unwrap()
+            .with_hms(12, 0, 0)
+            .unwrap()
+            .assume_utc()
+    }
+
+    fn oslo() -> UtcOffset {
+        UtcOffset::from_hms(2, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn be_before_and_after_success_should_not_panic() {
+        let later = noon() + Duration::from_secs(1);
+
+        noon().should().be_before(later);
+        later.should().be_after(noon());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected 2024-05-01T12:00:01.5Z to be before 2024-05-01T12:00:00Z, but the difference was +1.5s"
+    )]
+    fn be_before_fail_should_panic() {
+        (noon() + Duration::from_millis(1_500))
+            .should()
+            .be_before(noon());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected 2024-05-01T11:58:00Z to be after 2024-05-01T12:00:00Z, but the difference was -2m"
+    )]
+    fn be_after_fail_should_panic() {
+        (noon() - Duration::from_secs(120))
+            .should()
+            .be_after(noon());
+    }
+
+    #[test]
+    fn be_same_day_as_success_should_not_panic() {
+        noon()
+            .should()
+            .be_same_day_as(noon().to_offset(oslo()) + Duration::from_secs(3600));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected 2024-05-01T12:00:00Z to be on the same day as 2024-05-02T13:00:00+02:00, but it was on 2024-05-01 instead of 2024-05-02"
+    )]
+    fn be_same_day_as_fail_should_panic() {
+        noon()
+            .should()
+            .be_same_day_as((noon() + Duration::from_secs(23 * 3600)).to_offset(oslo()));
+    }
+
+    #[test]
+    fn be_weekday_success_should_not_panic() {
+        noon().should().be_weekday(Weekday::Wednesday);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "release: Expected 2024-05-01T12:00:00Z to be on a Friday, but it was on a Wednesday"
+    )]
+    fn be_weekday_fail_should_panic_with_path() {
+        noon().should().named("release").be_weekday(Weekday::Friday);
+    }
+
+    #[test]
+    fn be_in_timezone_success_should_not_panic() {
+        noon().to_offset(oslo()).should().be_in_timezone(oslo());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected 2024-05-01T12:00:00Z to be in timezone +02:00, but its offset was +00:00"
+    )]
+    fn be_in_timezone_fail_should_panic() {
+        noon().should().be_in_timezone(oslo());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected 2024-05-01T12:00:03Z to be within 1s of 2024-05-01T12:00:00Z, but the difference was +3s"
+    )]
+    fn be_close_to_fail_should_panic() {
+        (noon() + Duration::from_secs(3))
+            .should()
+            .be_close_to(noon())
+            .within(Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,346 @@
+use crate::core_panic::{catch_panic, describe_panic};
+use crate::Should;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Runs `f` once and returns its result along with how long it took.
+fn measure<R>(f: impl FnOnce() -> R) -> (R, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+impl<'a, F, R> Should<'a, F>
+where
+    F: Fn() -> R,
+{
+    /// Asserts that calling the closure once takes at most `limit`, and
+    /// returns its value.
+    ///
+    /// The closure always runs to completion; use
+    /// [`Should::complete_within_strict`] to stop waiting for closures that
+    /// might hang.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    /// use std::time::Duration;
+    ///
+    /// let sum = (|| (1..=100).sum::<u32>())
+    ///     .should()
+    ///     .complete_within(Duration::from_secs(1));
+    /// sum.should().eq(&5050);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the closure takes longer than `limit`, printing how long it took.
+    #[track_caller]
+    pub fn complete_within(&self, limit: Duration) -> R {
+        let (result, elapsed) = measure(self.actual());
+
+        self.check(elapsed <= limit, || {
+            format!(
+                "Expected closure to complete within {:?}, but it took {:?}",
+                limit, elapsed
+            )
+        });
+
+        result
+    }
+
+    /// Runs the closure `iterations` times, so that timing assertions can be
+    /// made on the median or 95th percentile instead of a single run.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    /// use std::time::Duration;
+    ///
+    /// (|| (1..=100).sum::<u32>())
+    ///     .should()
+    ///     .over_iterations(20)
+    ///     .warmup(3)
+    ///     .p95_within(Duration::from_millis(10));
+    /// ```
+    pub fn over_iterations(&self, iterations: usize) -> Timing<'_, 'a, F> {
+        Timing {
+            should: self,
+            warmup: 0,
+            iterations: iterations.max(1),
+        }
+    }
+}
+
+impl<'a, F, R> Should<'a, F>
+where
+    F: Fn() -> R + Clone + Send + 'static,
+    R: Send + 'static,
+{
+    /// Asserts that calling the closure takes at most `limit`, running it on
+    /// a separate thread so that a closure that hangs fails the test instead
+    /// of blocking it forever. Returns the closure's value.
+    ///
+    /// Threads cannot be cancelled, so on timeout the closure's thread is
+    /// leaked: it keeps running in the background, holding on to whatever the
+    /// closure captured, until the closure returns or the process exits. A
+    /// closure that never returns leaks its thread for the rest of the test
+    /// run, so prefer closures that can be stopped, e.g. by a channel or flag
+    /// they check.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    /// use std::time::Duration;
+    ///
+    /// (|| 42)
+    ///     .should()
+    ///     .complete_within_strict(Duration::from_secs(1))
+    ///     .should()
+    ///     .eq(&42);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the closure is still running after `limit`, or if it panics.
+    #[track_caller]
+    pub fn complete_within_strict(&self, limit: Duration) -> R {
+        let f = self.actual().clone();
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            // The receiver is gone once the assertion has timed out.
+            let _ = sender.send(catch_panic(f));
+        });
+
+        match receiver.recv_timeout(limit) {
+            Ok(Ok(result)) => result,
+            Ok(Err(payload)) => self.fail(format!(
+                "Expected closure to complete within {:?}, but it panicked with {}",
+                limit,
+                describe_panic(payload.as_ref())
+            )),
+            Err(RecvTimeoutError::Timeout) => self.fail(format!(
+                "Expected closure to complete within {:?}, but it was still running",
+                limit
+            )),
+            Err(RecvTimeoutError::Disconnected) => self.fail(format!(
+                "Expected closure to complete within {:?}, but its thread exited without a result",
+                limit
+            )),
+        }
+    }
+}
+
+/// Repeated timing of a closure, created by [`Should::over_iterations`].
+pub struct Timing<'s, 'a, F> {
+    should: &'s Should<'a, F>,
+    warmup: usize,
+    iterations: usize,
+}
+
+impl<'s, 'a, F, R> Timing<'s, 'a, F>
+where
+    F: Fn() -> R,
+{
+    /// Runs the closure `runs` extra times before measuring, discarding the
+    /// results.
+    pub fn warmup(mut self, runs: usize) -> Self {
+        self.warmup = runs;
+        self
+    }
+
+    /// Runs the warmup and measured iterations, returning the last value and
+    /// the sorted durations.
+    fn run(&self) -> (R, Vec<Duration>) {
+        let f = self.should.actual();
+
+        for _ in 0..self.warmup {
+            f();
+        }
+
+        let (mut result, first) = measure(f);
+        let mut durations = vec![first];
+
+        for _ in 1..self.iterations {
+            let (value, elapsed) = measure(f);
+            result = value;
+            durations.push(elapsed);
+        }
+
+        durations.sort();
+        (result, durations)
+    }
+
+    /// Fails unless `statistic` of the sorted durations is at most `limit`.
+    #[track_caller]
+    fn check_statistic(
+        &self,
+        name: &str,
+        limit: Duration,
+        statistic: fn(&[Duration]) -> Duration,
+    ) -> R {
+        let (result, durations) = self.run();
+        let value = statistic(&durations);
+
+        self.should.check(value <= limit, || {
+            format!(
+                "Expected {} duration over {} iteration(s) to be within {:?}, but it was {:?} (min {:?}, max {:?})",
+                name,
+                durations.len(),
+                limit,
+                value,
+                durations[0],
+                durations[durations.len() - 1]
+            )
+        });
+
+        result
+    }
+
+    /// Asserts that the median duration is at most `limit`, and returns the
+    /// value of the last run.
+    ///
+    /// # Panics
+    /// Panics if the median is above `limit`, printing it along with the
+    /// fastest and slowest runs.
+    #[track_caller]
+    pub fn median_within(&self, limit: Duration) -> R {
+        self.check_statistic("median", limit, |sorted| {
+            let middle = sorted.len() / 2;
+
+            if sorted.len() % 2 == 0 {
+                (sorted[middle - 1] + sorted[middle]) / 2
+            } else {
+                sorted[middle]
+            }
+        })
+    }
+
+    /// Asserts that the 95th percentile duration is at most `limit`, and
+    /// returns the value of the last run.
+    ///
+    /// # Panics
+    /// Panics if the 95th percentile is above `limit`, printing it along with
+    /// the fastest and slowest runs.
+    #[track_caller]
+    pub fn p95_within(&self, limit: Duration) -> R {
+        self.check_statistic("p95", limit, |sorted| {
+            let rank = (sorted.len() * 95 + 99) / 100;
+            sorted[rank.max(1) - 1]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Shouldable;
+    use std::cell::Cell;
+    use std::sync::{mpsc, Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+
+    fn slow() -> u32 {
+        thread::sleep(Duration::from_millis(20));
+        7
+    }
+
+    #[test]
+    fn complete_within_success_should_return_value() {
+        (|| 40 + 2)
+            .should()
+            .complete_within(Duration::from_secs(1))
+            .should()
+            .eq(42);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected closure to complete within 1ms, but it took")]
+    fn complete_within_fail_should_panic() {
+        slow.should().complete_within(Duration::from_millis(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "request: Expected closure to complete within 1ms")]
+    fn complete_within_should_include_path() {
+        slow.should()
+            .named("request")
+            .complete_within(Duration::from_millis(1));
+    }
+
+    #[test]
+    fn median_within_success_should_run_warmup_and_iterations() {
+        let calls = Cell::new(0);
+        (|| calls.set(calls.get() + 1))
+            .should()
+            .over_iterations(5)
+            .warmup(2)
+            .median_within(Duration::from_secs(1));
+
+        calls.get().should().eq(7);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected median duration over 3 iteration(s) to be within 1ms, but it was"
+    )]
+    fn median_within_fail_should_panic() {
+        slow.should()
+            .over_iterations(3)
+            .median_within(Duration::from_millis(1));
+    }
+
+    #[test]
+    fn p95_within_should_return_last_value() {
+        let calls = Cell::new(0);
+        (|| {
+            calls.set(calls.get() + 1);
+            calls.get()
+        })
+        .should()
+        .over_iterations(4)
+        .p95_within(Duration::from_secs(1))
+        .should()
+        .eq(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected p95 duration over 2 iteration(s) to be within 1ms")]
+    fn p95_within_fail_should_panic() {
+        slow.should()
+            .over_iterations(2)
+            .p95_within(Duration::from_millis(1));
+    }
+
+    #[test]
+    fn complete_within_strict_success_should_return_value() {
+        slow.should()
+            .complete_within_strict(Duration::from_secs(5))
+            .should()
+            .eq(&7);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected closure to complete within 10ms, but it was still running")]
+    fn complete_within_strict_hanging_should_panic() {
+        // The sender is dropped when the assertion fails and unwinds, which
+        // ends the closure instead of leaving its thread blocked.
+        let (_stop, stopped) = mpsc::channel::<()>();
+        let stopped = Arc::new(Mutex::new(stopped));
+
+        (move || {
+            let _ = stopped.lock().unwrap().recv();
+        })
+        .should()
+        .complete_within_strict(Duration::from_millis(10));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected closure to complete within 1s, but it panicked with \"boom\""
+    )]
+    fn complete_within_strict_panicking_should_panic() {
+        (|| -> u32 { panic!("boom") })
+            .should()
+            .complete_within_strict(Duration::from_secs(1));
+    }
+}
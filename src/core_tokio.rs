@@ -0,0 +1,286 @@
+use crate::core_panic::{catch_panic, describe_panic, panic_message};
+use crate::ShouldMut;
+use std::any::Any;
+use std::fmt::Debug;
+use std::future::{self, Future};
+use std::pin::Pin;
+use std::task::Poll;
+use std::time::Duration;
+use tokio::time;
+
+/// Awaits `future`, catching any panic raised while polling it.
+pub(crate) async fn catch_panic_async<Fut>(
+    future: &mut Fut,
+) -> Result<Fut::Output, Box<dyn Any + Send>>
+where
+    Fut: Future + Unpin + ?Sized,
+{
+    future::poll_fn(|cx| match catch_panic(|| Pin::new(&mut *future).poll(cx)) {
+        Ok(poll) => poll.map(Ok),
+        Err(payload) => Poll::Ready(Err(payload)),
+    })
+    .await
+}
+
+/// Assertions for futures that need a tokio runtime.
+///
+/// Timeouts use [`tokio::time`], so they complete instantly and
+/// deterministically in tests that run with paused time, such as
+/// `#[tokio::test(start_paused = true)]`.
+impl<'a, Fut> ShouldMut<'a, Fut>
+where
+    Fut: Future + Unpin + ?Sized,
+    Fut::Output: Debug,
+{
+    /// Asserts that the future resolves within `limit`, and returns its value.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let reply = Box::pin(async {
+    ///     tokio::time::sleep(Duration::from_millis(20)).await;
+    ///     "pong"
+    /// });
+    ///
+    /// reply
+    ///     .into_should()
+    ///     .resolve_within(Duration::from_millis(50))
+    ///     .await
+    ///     .should()
+    ///     .eq("pong");
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the future is still pending after `limit`.
+    pub async fn resolve_within(&mut self, limit: Duration) -> Fut::Output {
+        let future = self.actual_mut();
+
+        match time::timeout(limit, future).await {
+            Ok(value) => value,
+            Err(_) => self.fail(format!(
+                "Expected future to resolve within {:?}, but it was still pending",
+                limit
+            )),
+        }
+    }
+
+    /// Asserts that awaiting the future panics.
+    ///
+    /// The default panic output is suppressed while the future is polled.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// Box::pin(async { panic!("boom") })
+    ///     .into_should()
+    ///     .panic_async()
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the future resolves, printing its value.
+    pub async fn panic_async(&mut self) {
+        let future = self.actual_mut();
+
+        if let Ok(value) = catch_panic_async(future).await {
+            self.fail(format!(
+                "Expected future to panic, but it resolved to {:?}",
+                value
+            ));
+        }
+    }
+
+    /// Asserts that awaiting the future panics with a message containing
+    /// `expected`.
+    ///
+    /// # Panics
+    /// Panics if the future resolves, or panics with a different message.
+    pub async fn panic_with_message_containing_async(&mut self, expected: &str) {
+        let future = self.actual_mut();
+
+        match catch_panic_async(future).await {
+            Ok(value) => self.fail(format!(
+                "Expected future to panic with a message containing {:?}, but it resolved to {:?}",
+                expected, value
+            )),
+            Err(payload) => {
+                let matches = panic_message(payload.as_ref())
+                    .is_some_and(|message| message.contains(expected));
+
+                self.check(matches, || {
+                    format!(
+                        "Expected future to panic with a message containing {:?}, but it panicked with {}",
+                        expected,
+                        describe_panic(payload.as_ref())
+                    )
+                });
+            }
+        }
+    }
+
+    /// Asserts that awaiting the future does not panic, and returns its value.
+    ///
+    /// # Panics
+    /// Panics if the future panics, printing the panic message.
+    pub async fn not_panic_async(&mut self) -> Fut::Output {
+        let future = self.actual_mut();
+
+        match catch_panic_async(future).await {
+            Ok(value) => value,
+            Err(payload) => self.fail(format!(
+                "Expected future not to panic, but it panicked with {}",
+                describe_panic(payload.as_ref())
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{eventually, Shouldable};
+    use std::cell::Cell;
+    use std::future;
+    use std::time::Duration;
+    use tokio::time::{self, Instant};
+
+    async fn reply(delay: Duration) -> &'static str {
+        time::sleep(delay).await;
+        "pong"
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn resolve_within_success_should_return_value() {
+        Box::pin(reply(Duration::from_millis(20)))
+            .into_should()
+            .resolve_within(Duration::from_millis(50))
+            .await
+            .should()
+            .eq("pong");
+    }
+
+    #[tokio::test(start_paused = true)]
+    #[should_panic(expected = "Expected future to resolve within 50ms, but it was still pending")]
+    async fn resolve_within_timeout_should_panic() {
+        Box::pin(reply(Duration::from_secs(60)))
+            .into_should()
+            .resolve_within(Duration::from_millis(50))
+            .await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn resolve_within_should_not_wait_in_real_time() {
+        let start = std::time::Instant::now();
+        let virtual_start = Instant::now();
+
+        Box::pin(reply(Duration::from_secs(30)))
+            .into_should()
+            .resolve_within(Duration::from_secs(60))
+            .await;
+
+        (virtual_start.elapsed() >= Duration::from_secs(30))
+            .should()
+            .be_true();
+        (start.elapsed() < Duration::from_secs(5))
+            .should()
+            .be_true();
+    }
+
+    #[tokio::test]
+    async fn panic_async_success_should_not_panic() {
+        Box::pin(async { panic!("boom") })
+            .into_should()
+            .panic_async()
+            .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Expected future to panic, but it resolved to 1")]
+    async fn panic_async_fail_should_panic() {
+        future::ready(1).into_should().panic_async().await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn panic_with_message_containing_async_success_should_not_panic() {
+        Box::pin(async {
+            time::sleep(Duration::from_millis(5)).await;
+            panic!("connection reset by peer");
+        })
+        .into_should()
+        .panic_with_message_containing_async("reset")
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected future to panic with a message containing \"timeout\", but it panicked with \"boom\""
+    )]
+    async fn panic_with_message_containing_async_wrong_message_should_panic() {
+        Box::pin(async { panic!("boom") })
+            .into_should()
+            .panic_with_message_containing_async("timeout")
+            .await;
+    }
+
+    #[tokio::test]
+    async fn not_panic_async_success_should_return_value() {
+        future::ready(42)
+            .into_should()
+            .not_panic_async()
+            .await
+            .should()
+            .eq(42);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Expected future not to panic, but it panicked with \"boom\"")]
+    async fn not_panic_async_fail_should_panic() {
+        Box::pin(async { panic!("boom") })
+            .into_should()
+            .not_panic_async()
+            .await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn eventually_should_async_success_should_return_value() {
+        let calls = Cell::new(0);
+
+        eventually(|| async {
+            calls.set(calls.get() + 1);
+            calls.get()
+        })
+        .should_async(|calls| calls.should().eq(3))
+        .await
+        .should()
+        .eq(3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    #[should_panic(
+        expected = "Expected assertion to pass within 2s, but it still failed after 201 attempt(s). Last failure:\n  Expected 1 to equal to 2"
+    )]
+    async fn eventually_should_async_timeout_should_panic() {
+        eventually(|| async { 1 })
+            .within(Duration::from_secs(2))
+            .should_async(|value| value.should().eq(2))
+            .await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    #[should_panic(expected = "but it still failed after 8 attempt(s)")]
+    async fn eventually_should_async_backoff_should_grow_interval() {
+        // Waits of 10, 20, 40, 80, 160, 320 and 370ms fill the second.
+        eventually(|| async { 1 })
+            .backoff(2.0)
+            .should_async(|value| value.should().eq(2))
+            .await;
+    }
+}
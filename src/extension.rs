@@ -0,0 +1,149 @@
+/// Defines an extension trait that adds custom assertions to [`Should`](crate::Should).
+///
+/// Each method receives `&self` as a `Should<T>` and should fail through
+/// [`Should::check`](crate::Should::check) or [`Should::fail`](crate::Should::fail),
+/// so that failures carry the breadcrumb path and are formatted like the
+/// built-in assertions. The generated methods are `#[track_caller]`, so
+/// failures point at the calling test.
+///
+/// # Examples
+/// ```
+/// use shoulds::{define_assertion, Shouldable};
+///
+/// #[derive(Debug)]
+/// struct Invoice {
+///     total: u32,
+///     lines: Vec<u32>,
+/// }
+///
+/// define_assertion! {
+///     /// Assertions for invoices.
+///     pub trait InvoiceAssertions for Invoice {
+///         /// Asserts that the invoice total matches its lines.
+///         fn be_valid_invoice(&self) {
+///             let invoice = self.actual();
+///             self.check(invoice.lines.iter().sum::<u32>() == invoice.total, || {
+///                 format!("Expected {:?} to be a valid invoice", invoice)
+///             });
+///         }
+///
+///         fn have_total(&self, expected: u32) {
+///             self.field("total", |i| &i.total).eq(&expected);
+///         }
+///     }
+/// }
+///
+/// let invoice = Invoice {
+///     total: 30,
+///     lines: vec![10, 20],
+/// };
+///
+/// invoice.should().be_valid_invoice();
+/// invoice.should().have_total(30);
+/// ```
+///
+/// For checks that should also compose with `not`, `all_of` and friends,
+/// implement [`Matcher`](crate::Matcher) instead and use
+/// [`Should::match_with`](crate::Should::match_with).
+#[macro_export]
+macro_rules! define_assertion {
+    (
+        $(#[$meta:meta])*
+        $vis:vis trait $name:ident for $ty:ty {
+            $(
+                $(#[$method_meta:meta])*
+                fn $method:ident(&$self:ident $(, $arg:ident: $arg_ty:ty)* $(,)?) $(-> $ret:ty)? $body:block
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        $vis trait $name {
+            $(
+                $(#[$method_meta])*
+                fn $method(&self $(, $arg: $arg_ty)*) $(-> $ret)?;
+            )*
+        }
+
+        impl<'__should> $name for $crate::Should<'__should, $ty> {
+            $(
+                #[track_caller]
+                fn $method(&$self $(, $arg: $arg_ty)*) $(-> $ret)? $body
+            )*
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Shouldable;
+
+    #[derive(Debug)]
+    struct Invoice {
+        total: u32,
+        lines: Vec<u32>,
+    }
+
+    define_assertion! {
+        trait InvoiceAssertions for Invoice {
+            fn be_valid_invoice(&self) {
+                let invoice = self.actual();
+                self.check(invoice.lines.iter().sum::<u32>() == invoice.total, || {
+                    format!("Expected {:?} to be a valid invoice", invoice)
+                });
+            }
+
+            fn have_line_count(&self, expected: usize) -> &Self {
+                self.check(self.actual().lines.len() == expected, || {
+                    format!("Expected {} line(s), but got {}", expected, self.actual().lines.len())
+                });
+                self
+            }
+        }
+    }
+
+    fn invoice(total: u32) -> Invoice {
+        Invoice {
+            total,
+            lines: vec![10, 20],
+        }
+    }
+
+    #[test]
+    fn custom_assertion_success_should_not_panic() {
+        invoice(30).should().be_valid_invoice();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected Invoice { total: 40, lines: [10, 20] } to be a valid invoice"
+    )]
+    fn custom_assertion_fail_should_panic() {
+        invoice(40).should().be_valid_invoice();
+    }
+
+    #[test]
+    fn custom_assertion_should_chain() {
+        invoice(30).should().have_line_count(2).be_valid_invoice();
+    }
+
+    #[test]
+    #[should_panic(expected = "order.invoice: Expected 2 line(s), but got 3")]
+    fn custom_assertion_should_include_path() {
+        struct Order {
+            invoice: Invoice,
+        }
+
+        let order = Order {
+            invoice: Invoice {
+                total: 6,
+                lines: vec![1, 2, 3],
+            },
+        };
+
+        order
+            .should()
+            .named("order")
+            .field("invoice", |o| &o.invoice)
+            .have_line_count(2);
+    }
+}
@@ -18,11 +18,70 @@
 //!
 //! This crate is experimental and evolving. The API may change in 0.x versions as we build toward a richer set of assertions and improved error messages.
 
+extern crate self as shoulds;
+
+#[cfg(feature = "anyhow")]
+mod core_anyhow;
 mod core_bool;
+#[cfg(feature = "chrono")]
+mod core_chrono;
+mod core_diff;
 mod core_eq;
+mod core_error;
+#[cfg(feature = "eyre")]
+mod core_eyre;
+mod core_future;
+mod core_golden;
+mod core_inline_snapshot;
+mod core_io;
+mod core_iter;
+mod core_match;
+mod core_matcher;
 mod core_ne;
 mod core_option;
+mod core_panic;
+mod core_read;
+mod core_redact;
 mod core_result;
+mod core_slice;
+mod core_snapshot;
+mod core_str;
+#[cfg(feature = "futures")]
+mod core_stream;
+mod core_text_diff;
+mod core_time;
+#[cfg(feature = "time")]
+mod core_time_crate;
+mod core_timing;
+#[cfg(feature = "tokio")]
+mod core_tokio;
+mod extension;
 mod should;
+#[cfg(test)]
+mod test_support;
+
+pub mod matchers;
+pub mod polling;
+
+pub use core_diff::{ApproxDiff, Difference, ShouldDiff};
+pub use core_eq::Expected;
+pub use core_golden::Newlines;
+pub use core_redact::Redactions;
+#[cfg(feature = "futures")]
+pub use core_stream::StreamAssertions;
+pub use core_time::{CloseTo, Within};
+pub use core_timing::Timing;
+pub use matchers::Matcher;
+pub use polling::{consistently, eventually};
+pub use should::{Should, ShouldMut, ShouldOwned, Shouldable};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::core_match::{
+        should_at, EqPattern, MatcherPattern, Pattern, RangeLike, RangePattern, ShouldSubject,
+        Subject, ValueSubject,
+    };
+}
 
-pub use should::{Should, Shouldable};
+#[cfg(feature = "derive")]
+pub use shoulds_derive::ShouldDiff;
@@ -0,0 +1,441 @@
+//! Composable matchers for use with [`Should::match_with`](crate::Should::match_with).
+//!
+//! A [`Matcher`] checks a value and describes both the expectation and the
+//! mismatch, so matchers can be combined with [`all_of`], [`any_of`], [`not`]
+//! and [`each`].
+//!
+//! ```
+//! use shoulds::matchers::{all_of, each, eq, not};
+//! use shoulds::Shouldable;
+//!
+//! let values = vec![1, 2, 3];
+//! values.should().match_with(each(all_of((not(eq(0)), not(eq(4))))));
+//! ```
+
+use std::fmt::Debug;
+
+/// A reusable, composable check against a value of type `T`.
+pub trait Matcher<T: ?Sized> {
+    /// Returns true if `actual` satisfies the matcher.
+    fn check(&self, actual: &T) -> bool;
+
+    /// Describes what the matcher expects, e.g. `equal to 42`.
+    fn describe(&self) -> String;
+
+    /// Describes why `actual` did not satisfy the matcher, e.g. `was 41`.
+    fn describe_mismatch(&self, actual: &T) -> String
+    where
+        T: Debug,
+    {
+        format!("was {:?}", actual)
+    }
+}
+
+impl<T: ?Sized, M: Matcher<T> + ?Sized> Matcher<T> for &M {
+    fn check(&self, actual: &T) -> bool {
+        (**self).check(actual)
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String
+    where
+        T: Debug,
+    {
+        (**self).describe_mismatch(actual)
+    }
+}
+
+impl<T: ?Sized, M: Matcher<T> + ?Sized> Matcher<T> for Box<M> {
+    fn check(&self, actual: &T) -> bool {
+        (**self).check(actual)
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String
+    where
+        T: Debug,
+    {
+        (**self).describe_mismatch(actual)
+    }
+}
+
+/// Matcher returned by [`eq`].
+pub struct EqualTo<U>(U);
+
+/// Matches values equal to `expected`.
+pub fn eq<U>(expected: U) -> EqualTo<U> {
+    EqualTo(expected)
+}
+
+impl<T, U> Matcher<T> for EqualTo<U>
+where
+    T: PartialEq<U> + ?Sized,
+    U: Debug,
+{
+    fn check(&self, actual: &T) -> bool {
+        *actual == self.0
+    }
+
+    fn describe(&self) -> String {
+        format!("equal to {:?}", self.0)
+    }
+}
+
+/// Matcher returned by [`ne`].
+pub struct NotEqualTo<U>(U);
+
+/// Matches values not equal to `unexpected`.
+pub fn ne<U>(unexpected: U) -> NotEqualTo<U> {
+    NotEqualTo(unexpected)
+}
+
+impl<T, U> Matcher<T> for NotEqualTo<U>
+where
+    T: PartialEq<U> + ?Sized,
+    U: Debug,
+{
+    fn check(&self, actual: &T) -> bool {
+        *actual != self.0
+    }
+
+    fn describe(&self) -> String {
+        format!("not equal to {:?}", self.0)
+    }
+}
+
+/// Matcher returned by [`some`].
+pub struct IsSome;
+
+/// Matches `Option`s that are `Some`.
+pub fn some() -> IsSome {
+    IsSome
+}
+
+impl<T> Matcher<Option<T>> for IsSome {
+    fn check(&self, actual: &Option<T>) -> bool {
+        actual.is_some()
+    }
+
+    fn describe(&self) -> String {
+        "Some".to_string()
+    }
+}
+
+/// Matcher returned by [`none`].
+pub struct IsNone;
+
+/// Matches `Option`s that are `None`.
+pub fn none() -> IsNone {
+    IsNone
+}
+
+impl<T> Matcher<Option<T>> for IsNone {
+    fn check(&self, actual: &Option<T>) -> bool {
+        actual.is_none()
+    }
+
+    fn describe(&self) -> String {
+        "None".to_string()
+    }
+}
+
+/// Matcher returned by [`ok`].
+pub struct IsOk;
+
+/// Matches `Result`s that are `Ok`.
+pub fn ok() -> IsOk {
+    IsOk
+}
+
+impl<T, E> Matcher<Result<T, E>> for IsOk {
+    fn check(&self, actual: &Result<T, E>) -> bool {
+        actual.is_ok()
+    }
+
+    fn describe(&self) -> String {
+        "Ok".to_string()
+    }
+}
+
+/// Matcher returned by [`err`].
+pub struct IsErr;
+
+/// Matches `Result`s that are `Err`.
+pub fn err() -> IsErr {
+    IsErr
+}
+
+impl<T, E> Matcher<Result<T, E>> for IsErr {
+    fn check(&self, actual: &Result<T, E>) -> bool {
+        actual.is_err()
+    }
+
+    fn describe(&self) -> String {
+        "Err".to_string()
+    }
+}
+
+/// Matcher returned by [`not`].
+pub struct Not<M>(M);
+
+/// Inverts a matcher.
+pub fn not<M>(matcher: M) -> Not<M> {
+    Not(matcher)
+}
+
+impl<T: ?Sized, M: Matcher<T>> Matcher<T> for Not<M> {
+    fn check(&self, actual: &T) -> bool {
+        !self.0.check(actual)
+    }
+
+    fn describe(&self) -> String {
+        format!("not {}", self.0.describe())
+    }
+}
+
+/// A list of matchers combined by [`all_of`] or [`any_of`].
+///
+/// Implemented for tuples of up to eight matchers and for
+/// `Vec<Box<dyn Matcher<T>>>`.
+pub trait MatcherList<T: ?Sized> {
+    /// Returns the matchers in the list.
+    fn matchers(&self) -> Vec<&dyn Matcher<T>>;
+}
+
+impl<T: ?Sized> MatcherList<T> for Vec<Box<dyn Matcher<T>>> {
+    fn matchers(&self) -> Vec<&dyn Matcher<T>> {
+        self.iter().map(|matcher| matcher.as_ref()).collect()
+    }
+}
+
+macro_rules! impl_matcher_list {
+    ($($name:ident: $index:tt),+) => {
+        impl<T: ?Sized, $($name: Matcher<T>),+> MatcherList<T> for ($($name,)+) {
+            fn matchers(&self) -> Vec<&dyn Matcher<T>> {
+                vec![$(&self.$index),+]
+            }
+        }
+    };
+}
+
+impl_matcher_list!(A: 0);
+impl_matcher_list!(A: 0, B: 1);
+impl_matcher_list!(A: 0, B: 1, C: 2);
+impl_matcher_list!(A: 0, B: 1, C: 2, D: 3);
+impl_matcher_list!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_matcher_list!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+impl_matcher_list!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+impl_matcher_list!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+
+fn describe_list<T: ?Sized>(matchers: &[&dyn Matcher<T>], separator: &str) -> String {
+    matchers
+        .iter()
+        .map(|matcher| matcher.describe())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Matcher returned by [`all_of`].
+pub struct AllOf<L>(L);
+
+/// Matches values that satisfy every matcher in `matchers`.
+pub fn all_of<L>(matchers: L) -> AllOf<L> {
+    AllOf(matchers)
+}
+
+impl<T: ?Sized, L: MatcherList<T>> Matcher<T> for AllOf<L> {
+    fn check(&self, actual: &T) -> bool {
+        self.0
+            .matchers()
+            .iter()
+            .all(|matcher| matcher.check(actual))
+    }
+
+    fn describe(&self) -> String {
+        format!("all of ({})", describe_list(&self.0.matchers(), " and "))
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String
+    where
+        T: Debug,
+    {
+        self.0
+            .matchers()
+            .iter()
+            .filter(|matcher| !matcher.check(actual))
+            .map(|matcher| {
+                format!(
+                    "{} (expected {})",
+                    matcher.describe_mismatch(actual),
+                    matcher.describe()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Matcher returned by [`any_of`].
+pub struct AnyOf<L>(L);
+
+/// Matches values that satisfy at least one matcher in `matchers`.
+pub fn any_of<L>(matchers: L) -> AnyOf<L> {
+    AnyOf(matchers)
+}
+
+impl<T: ?Sized, L: MatcherList<T>> Matcher<T> for AnyOf<L> {
+    fn check(&self, actual: &T) -> bool {
+        self.0
+            .matchers()
+            .iter()
+            .any(|matcher| matcher.check(actual))
+    }
+
+    fn describe(&self) -> String {
+        format!("any of ({})", describe_list(&self.0.matchers(), " or "))
+    }
+}
+
+/// Matcher returned by [`each`].
+pub struct Each<M>(M);
+
+/// Matches collections whose every item satisfies `matcher`.
+pub fn each<M>(matcher: M) -> Each<M> {
+    Each(matcher)
+}
+
+impl<E: Debug, M: Matcher<E>> Matcher<[E]> for Each<M> {
+    fn check(&self, actual: &[E]) -> bool {
+        actual.iter().all(|item| self.0.check(item))
+    }
+
+    fn describe(&self) -> String {
+        format!("each item {}", self.0.describe())
+    }
+
+    fn describe_mismatch(&self, actual: &[E]) -> String
+    where
+        [E]: Debug,
+    {
+        actual
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| !self.0.check(item))
+            .map(|(index, item)| format!("item [{}] {}", index, self.0.describe_mismatch(item)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl<E: Debug, M: Matcher<E>> Matcher<Vec<E>> for Each<M> {
+    fn check(&self, actual: &Vec<E>) -> bool {
+        Matcher::<[E]>::check(self, actual)
+    }
+
+    fn describe(&self) -> String {
+        Matcher::<[E]>::describe(self)
+    }
+
+    fn describe_mismatch(&self, actual: &Vec<E>) -> String
+    where
+        Vec<E>: Debug,
+    {
+        Matcher::<[E]>::describe_mismatch(self, actual)
+    }
+}
+
+impl<E: Debug, M: Matcher<E>, const N: usize> Matcher<[E; N]> for Each<M> {
+    fn check(&self, actual: &[E; N]) -> bool {
+        Matcher::<[E]>::check(self, actual)
+    }
+
+    fn describe(&self) -> String {
+        Matcher::<[E]>::describe(self)
+    }
+
+    fn describe_mismatch(&self, actual: &[E; N]) -> String
+    where
+        [E; N]: Debug,
+    {
+        Matcher::<[E]>::describe_mismatch(self, actual)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Shouldable;
+
+    #[test]
+    fn eq_should_match_equal_values() {
+        let matcher = eq(42);
+        matcher.check(&42).should().be_true();
+        matcher.check(&41).should().be_false();
+        Matcher::<i32>::describe(&matcher)
+            .should()
+            .eq("equal to 42".to_string());
+    }
+
+    #[test]
+    fn not_should_invert_matcher() {
+        let matcher = not(eq(42));
+        matcher.check(&41).should().be_true();
+        matcher.check(&42).should().be_false();
+        Matcher::<i32>::describe(&matcher)
+            .should()
+            .eq("not equal to 42".to_string());
+    }
+
+    #[test]
+    fn some_and_ok_should_match_variants() {
+        some().check(&Some(1)).should().be_true();
+        none().check(&Some(1)).should().be_false();
+        ok().check(&Ok::<i32, &str>(1)).should().be_true();
+        err().check(&Ok::<i32, &str>(1)).should().be_false();
+    }
+
+    #[test]
+    fn all_of_should_require_every_matcher() {
+        let matcher = all_of((ne(1), ne(2)));
+        matcher.check(&3).should().be_true();
+        matcher.check(&2).should().be_false();
+        matcher
+            .describe_mismatch(&2)
+            .should()
+            .eq("was 2 (expected not equal to 2)".to_string());
+    }
+
+    #[test]
+    fn any_of_should_require_one_matcher() {
+        let matcher = any_of((eq(1), eq(2)));
+        matcher.check(&2).should().be_true();
+        matcher.check(&3).should().be_false();
+        Matcher::<i32>::describe(&matcher)
+            .should()
+            .eq("any of (equal to 1 or equal to 2)".to_string());
+    }
+
+    #[test]
+    fn each_should_report_failing_items() {
+        let matcher = each(ne(0));
+        let values = vec![1, 0, 2, 0];
+        matcher.check(&values).should().be_false();
+        matcher
+            .describe_mismatch(&values)
+            .should()
+            .eq("item [1] was 0, item [3] was 0".to_string());
+    }
+
+    #[test]
+    fn boxed_matchers_should_compose() {
+        let matchers: Vec<Box<dyn Matcher<Option<i32>>>> =
+            vec![Box::new(some()), Box::new(ne(Some(3)))];
+        all_of(matchers).check(&Some(2)).should().be_true();
+    }
+}
@@ -0,0 +1,513 @@
+//! Polling assertions for values that change over time, such as state shared
+//! with background threads.
+//!
+//! [`eventually`] retries an assertion until it passes, and [`consistently`]
+//! checks that it keeps passing. With the `tokio` feature,
+//! [`Eventually::should_async`] retries assertions on async values. Both run the assertion through
+//! [`Should`](crate::Should) as usual; failed attempts are caught and their
+//! panic output is suppressed.
+//!
+//! ```
+//! use shoulds::{eventually, Shouldable};
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//! use std::sync::Arc;
+//! use std::thread;
+//! use std::time::Duration;
+//!
+//! let processed = Arc::new(AtomicUsize::new(0));
+//! let worker = Arc::clone(&processed);
+//! thread::spawn(move || {
+//!     for _ in 0..3 {
+//!         thread::sleep(Duration::from_millis(5));
+//!         worker.fetch_add(1, Ordering::SeqCst);
+//!     }
+//! });
+//!
+//! eventually(|| processed.load(Ordering::SeqCst))
+//!     .within(Duration::from_secs(2))
+//!     .every(Duration::from_millis(10))
+//!     .should(|count| count.should().eq(&3));
+//! ```
+
+use crate::core_panic::{catch_panic, panic_message};
+#[cfg(feature = "tokio")]
+use crate::core_tokio::catch_panic_async;
+use std::any::Any;
+#[cfg(feature = "tokio")]
+use std::future::Future;
+use std::iter;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+const DEFAULT_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Starts an assertion that must pass at some point within a timeout.
+///
+/// The closure is evaluated again before every attempt. See [`Eventually`].
+pub fn eventually<T, F>(poll: F) -> Eventually<F>
+where
+    F: FnMut() -> T,
+{
+    Eventually {
+        poll,
+        timeout: DEFAULT_TIMEOUT,
+        interval: DEFAULT_INTERVAL,
+        backoff: 1.0,
+    }
+}
+
+/// Starts an assertion that must keep passing for a period of time.
+///
+/// The closure is evaluated again before every attempt. See [`Consistently`].
+pub fn consistently<T, F>(poll: F) -> Consistently<F>
+where
+    F: FnMut() -> T,
+{
+    Consistently {
+        poll,
+        duration: DEFAULT_TIMEOUT,
+        interval: DEFAULT_INTERVAL,
+    }
+}
+
+/// Formats a caught assertion failure for polling failure messages.
+pub(crate) fn describe_failure(payload: &(dyn Any + Send)) -> &str {
+    panic_message(payload).unwrap_or("a non-string payload")
+}
+
+/// Retries an assertion until it passes or the timeout elapses.
+///
+/// Created with [`eventually`]. Defaults to a 1 second timeout, polling every
+/// 10 milliseconds without backoff.
+pub struct Eventually<F> {
+    poll: F,
+    timeout: Duration,
+    interval: Duration,
+    backoff: f64,
+}
+
+impl<F> Eventually<F> {
+    /// Sets how long to keep retrying.
+    pub fn within(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets how long to wait between attempts.
+    ///
+    /// # Panics
+    /// Panics if `interval` is zero, which would retry in a busy loop.
+    #[track_caller]
+    pub fn every(mut self, interval: Duration) -> Self {
+        assert!(
+            !interval.is_zero(),
+            "Polling interval must be greater than zero"
+        );
+
+        self.interval = interval;
+        self
+    }
+
+    /// Multiplies the wait between attempts by `factor` after each failure.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::{eventually, Shouldable};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let start = Instant::now();
+    /// eventually(|| start.elapsed())
+    ///     .every(Duration::from_millis(1))
+    ///     .backoff(2.0)
+    ///     .should(|elapsed| (*elapsed >= Duration::from_millis(20)).should().be_true());
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `factor` is less than 1.0 or not a finite number.
+    #[track_caller]
+    pub fn backoff(mut self, factor: f64) -> Self {
+        assert!(
+            factor.is_finite() && factor >= 1.0,
+            "Backoff factor must be a finite number of at least 1.0, but was {}",
+            factor
+        );
+
+        self.backoff = factor;
+        self
+    }
+
+    /// Returns the waits between attempts, starting with the interval and
+    /// growing by the backoff factor, each capped at the timeout.
+    fn intervals(&self) -> impl Iterator<Item = Duration> {
+        let (timeout, backoff) = (self.timeout, self.backoff);
+
+        iter::successors(Some(self.interval.min(timeout)), move |interval| {
+            Some(
+                Duration::try_from_secs_f64(interval.as_secs_f64() * backoff)
+                    .unwrap_or(Duration::MAX)
+                    .min(timeout),
+            )
+        })
+    }
+
+    /// Fails after the timeout has elapsed, reporting the last failure.
+    #[track_caller]
+    fn timed_out(&self, attempts: usize, failure: &(dyn Any + Send)) -> ! {
+        panic!(
+            "Expected assertion to pass within {:?}, but it still failed after {} attempt(s). Last failure:\n  {}",
+            self.timeout,
+            attempts,
+            describe_failure(failure)
+        );
+    }
+
+    /// Evaluates the closure and runs `assertion` on its value until the
+    /// assertion passes, returning the value that passed.
+    ///
+    /// # Panics
+    /// Panics once the timeout has elapsed, reporting the number of attempts
+    /// and the last failure.
+    #[track_caller]
+    pub fn should<T>(mut self, assertion: impl Fn(&T)) -> T
+    where
+        F: FnMut() -> T,
+    {
+        let start = Instant::now();
+        let mut intervals = self.intervals();
+        let mut attempts = 0;
+
+        loop {
+            attempts += 1;
+
+            let failure = match catch_panic(|| {
+                let value = (self.poll)();
+                assertion(&value);
+                value
+            }) {
+                Ok(value) => return value,
+                Err(payload) => payload,
+            };
+
+            let elapsed = start.elapsed();
+
+            if elapsed >= self.timeout {
+                self.timed_out(attempts, failure.as_ref());
+            }
+
+            let interval = intervals.next().unwrap_or(self.timeout);
+            thread::sleep(interval.min(self.timeout - elapsed));
+        }
+    }
+
+    /// Awaits the future returned by the closure and runs `assertion` on its
+    /// value until the assertion passes, returning the value that passed.
+    ///
+    /// This is the async counterpart of [`Eventually::should`], enabled by the
+    /// `tokio` feature. Waits use [`tokio::time`], so paused time skips them.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::{eventually, Shouldable};
+    /// use std::sync::atomic::{AtomicU32, Ordering};
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let polls = AtomicU32::new(0);
+    ///
+    /// eventually(|| async { polls.fetch_add(1, Ordering::SeqCst) + 1 })
+    ///     .within(Duration::from_secs(2))
+    ///     .every(Duration::from_millis(10))
+    ///     .backoff(2.0)
+    ///     .should_async(|polls| polls.should().eq(&5))
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics once the timeout has elapsed, reporting the number of attempts
+    /// and the last failure.
+    #[cfg(feature = "tokio")]
+    pub async fn should_async<T, Fut>(mut self, assertion: impl Fn(&T)) -> T
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = T>,
+    {
+        let start = tokio::time::Instant::now();
+        let mut intervals = self.intervals();
+        let mut attempts = 0;
+
+        loop {
+            attempts += 1;
+
+            let mut attempt = Box::pin(async {
+                let value = (self.poll)().await;
+                assertion(&value);
+                value
+            });
+
+            let failure = match catch_panic_async(&mut attempt).await {
+                Ok(value) => return value,
+                Err(payload) => payload,
+            };
+            drop(attempt);
+
+            let elapsed = start.elapsed();
+
+            if elapsed >= self.timeout {
+                self.timed_out(attempts, failure.as_ref());
+            }
+
+            let interval = intervals.next().unwrap_or(self.timeout);
+            tokio::time::sleep(interval.min(self.timeout - elapsed)).await;
+        }
+    }
+}
+
+/// Checks that an assertion keeps passing for a period of time.
+///
+/// Created with [`consistently`]. Defaults to checking for 1 second, every
+/// 10 milliseconds.
+pub struct Consistently<F> {
+    poll: F,
+    duration: Duration,
+    interval: Duration,
+}
+
+impl<F> Consistently<F> {
+    /// Sets how long the assertion has to keep passing.
+    pub fn for_(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets how long to wait between attempts.
+    ///
+    /// # Panics
+    /// Panics if `interval` is zero, which would retry in a busy loop.
+    #[track_caller]
+    pub fn every(mut self, interval: Duration) -> Self {
+        assert!(
+            !interval.is_zero(),
+            "Polling interval must be greater than zero"
+        );
+
+        self.interval = interval;
+        self
+    }
+
+    /// Evaluates the closure and runs `assertion` on its value until the
+    /// duration has elapsed, returning the last value.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::{consistently, Shouldable};
+    /// use std::time::Duration;
+    ///
+    /// let limit = 10;
+    /// consistently(|| limit)
+    ///     .for_(Duration::from_millis(30))
+    ///     .should(|limit| limit.should().eq(&10));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics on the first failed attempt, reporting when it failed and the
+    /// failure.
+    #[track_caller]
+    pub fn should<T>(mut self, assertion: impl Fn(&T)) -> T
+    where
+        F: FnMut() -> T,
+    {
+        let start = Instant::now();
+        let mut attempts = 0;
+
+        loop {
+            attempts += 1;
+
+            let value = match catch_panic(|| {
+                let value = (self.poll)();
+                assertion(&value);
+                value
+            }) {
+                Ok(value) => value,
+                Err(payload) => panic!(
+                    "Expected assertion to hold for {:?}, but it failed on attempt {} after {:?}:\n  {}",
+                    self.duration,
+                    attempts,
+                    start.elapsed(),
+                    describe_failure(payload.as_ref())
+                ),
+            };
+
+            let elapsed = start.elapsed();
+
+            if elapsed >= self.duration {
+                return value;
+            }
+
+            thread::sleep(self.interval.min(self.duration - elapsed));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{consistently, eventually};
+    use crate::Shouldable;
+    use std::cell::Cell;
+    use std::time::Duration;
+
+    #[test]
+    fn eventually_success_should_return_value() {
+        let calls = Cell::new(0);
+        let value = eventually(|| {
+            calls.set(calls.get() + 1);
+            calls.get()
+        })
+        .every(Duration::from_millis(1))
+        .should(|calls| calls.should().eq(3));
+
+        value.should().eq(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected assertion to pass within 20ms, but it still failed after")]
+    fn eventually_timeout_should_panic() {
+        eventually(|| 1)
+            .within(Duration::from_millis(20))
+            .every(Duration::from_millis(5))
+            .should(|value| value.should().eq(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Last failure:\n  Expected 1 to equal to 2")]
+    fn eventually_should_report_last_failure() {
+        eventually(|| 1)
+            .within(Duration::from_millis(10))
+            .should(|value| value.should().eq(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "failed after 1 attempt(s)")]
+    fn eventually_should_attempt_at_least_once() {
+        eventually(|| 1)
+            .within(Duration::ZERO)
+            .should(|value| value.should().eq(2));
+    }
+
+    #[test]
+    fn eventually_should_retry_when_closure_panics() {
+        let calls = Cell::new(0);
+        eventually(|| {
+            calls.set(calls.get() + 1);
+            if calls.get() < 3 {
+                panic!("not ready");
+            }
+            calls.get()
+        })
+        .every(Duration::from_millis(1))
+        .should(|_| {});
+    }
+
+    #[test]
+    fn eventually_backoff_should_grow_interval() {
+        let intervals: Vec<_> = eventually(|| 1)
+            .within(Duration::from_millis(50))
+            .every(Duration::from_millis(4))
+            .backoff(2.0)
+            .intervals()
+            .take(6)
+            .map(|interval| interval.as_millis())
+            .collect();
+
+        intervals.should().eq(&[4, 8, 16, 32, 50, 50]);
+    }
+
+    #[test]
+    fn eventually_without_backoff_should_keep_interval() {
+        let intervals: Vec<_> = eventually(|| 1)
+            .every(Duration::from_millis(4))
+            .intervals()
+            .take(3)
+            .map(|interval| interval.as_millis())
+            .collect();
+
+        intervals.should().eq(&[4, 4, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Polling interval must be greater than zero")]
+    fn eventually_zero_interval_should_panic() {
+        let _ = eventually(|| 1).every(Duration::ZERO);
+    }
+
+    #[test]
+    #[should_panic(expected = "Backoff factor must be a finite number of at least 1.0, but was -2")]
+    fn eventually_negative_backoff_should_panic() {
+        let _ = eventually(|| 1).backoff(-2.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Backoff factor must be a finite number of at least 1.0, but was NaN"
+    )]
+    fn eventually_nan_backoff_should_panic() {
+        let _ = eventually(|| 1).backoff(f64::NAN);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Backoff factor must be a finite number of at least 1.0, but was 0.5"
+    )]
+    fn eventually_shrinking_backoff_should_panic() {
+        let _ = eventually(|| 1).backoff(0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected assertion to pass within 30ms")]
+    fn eventually_huge_backoff_should_not_overflow() {
+        eventually(|| 1)
+            .within(Duration::from_millis(30))
+            .every(Duration::from_millis(1))
+            .backoff(1e300)
+            .should(|value| value.should().eq(2));
+    }
+
+    #[test]
+    fn consistently_success_should_return_value() {
+        consistently(|| 10)
+            .for_(Duration::from_millis(20))
+            .every(Duration::from_millis(5))
+            .should(|value| value.should().eq(10))
+            .should()
+            .eq(10);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected assertion to hold for 1s, but it failed on attempt 3 after"
+    )]
+    fn consistently_fail_should_panic() {
+        let calls = Cell::new(0);
+        consistently(|| {
+            calls.set(calls.get() + 1);
+            calls.get()
+        })
+        .for_(Duration::from_secs(1))
+        .every(Duration::from_millis(1))
+        .should(|calls| (*calls < 3).should().be_true());
+    }
+
+    #[test]
+    #[should_panic(expected = "failed on attempt 1 after")]
+    fn consistently_should_fail_on_first_attempt() {
+        consistently(|| false).should(|value| value.should().be_true());
+    }
+
+    #[test]
+    #[should_panic(expected = "Polling interval must be greater than zero")]
+    fn consistently_zero_interval_should_panic() {
+        let _ = consistently(|| 1).every(Duration::ZERO);
+    }
+}
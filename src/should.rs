@@ -1,16 +1,178 @@
+use std::fmt::Display;
+use std::ops::{Deref, DerefMut};
+
 /// A wrapper type that provides fluent-style assertions for a given value.
 ///
-/// Constructed using the [`Shouldable::should`] method.
-pub struct Should<'a, T> {
-    actual: &'a T,
+/// Constructed using the [`Shouldable::should`] method. Assertions that
+/// consume or mutate the value are on [`ShouldMut`] and [`ShouldOwned`]
+/// instead.
+pub struct Should<'a, T: ?Sized> {
+    actual: Actual<'a, T>,
+    path: Option<String>,
 }
 
-/// Returns a reference to the underlying value.
-///
-/// This can be useful for writing custom assertions or debugging.
-impl<'a, T> Should<'a, T> {
+/// How a [`Should`] holds the value under test.
+enum Actual<'a, T: ?Sized> {
+    Borrowed(&'a T),
+    Mutable(&'a mut T),
+    Owned(Box<T>),
+}
+
+impl<'a, T: ?Sized> Should<'a, T> {
+    pub(crate) fn new(actual: &'a T, path: Option<String>) -> Self {
+        Should {
+            actual: Actual::Borrowed(actual),
+            path,
+        }
+    }
+
+    /// Returns a reference to the underlying value.
+    ///
+    /// This can be useful for writing custom assertions or debugging.
     pub fn actual(&self) -> &T {
-        self.actual
+        match &self.actual {
+            Actual::Borrowed(actual) => actual,
+            Actual::Mutable(actual) => actual,
+            Actual::Owned(actual) => actual,
+        }
+    }
+
+    /// Returns the breadcrumb path of the value under test, if any.
+    ///
+    /// The path is built up by [`Should::named`] and [`Should::field`] and is
+    /// prepended to failure messages.
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+
+    /// Names the value under test so that failure messages start with `name`.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// let count = 3;
+    /// count.should().named("count").eq(&3);
+    /// ```
+    pub fn named(mut self, name: impl Into<String>) -> Self {
+        self.path = Some(name.into());
+        self
+    }
+
+    /// Drills down into a part of the value, appending `name` to the breadcrumb path.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// struct Address {
+    ///     city: String,
+    /// }
+    ///
+    /// struct User {
+    ///     address: Address,
+    /// }
+    ///
+    /// let user = User {
+    ///     address: Address {
+    ///         city: "Oslo".to_string(),
+    ///     },
+    /// };
+    ///
+    /// user.should()
+    ///     .named("user")
+    ///     .field("address.city", |u| &u.address.city)
+    ///     .eq(&"Oslo".to_string());
+    /// ```
+    ///
+    /// A failure in the example above would read
+    /// `user.address.city: Expected "Bergen" to equal to "Oslo"`.
+    pub fn field<'b, U: ?Sized>(
+        &'b self,
+        name: &str,
+        project: impl FnOnce(&'b T) -> &'b U,
+    ) -> Should<'b, U> {
+        let path = match &self.path {
+            Some(parent) => format!("{}.{}", parent, name),
+            None => name.to_string(),
+        };
+
+        Should::new(project(self.actual()), Some(path))
+    }
+
+    /// Projects the value into another one, keeping the current breadcrumb path.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// let pair = (1, "one");
+    /// pair.should().map(|p| &p.0).eq(&1);
+    /// ```
+    pub fn map<'b, U: ?Sized>(&'b self, project: impl FnOnce(&'b T) -> &'b U) -> Should<'b, U> {
+        Should::new(project(self.actual()), self.path.clone())
+    }
+
+    /// Dereferences the value, keeping the current breadcrumb path.
+    ///
+    /// This makes assertions written for `str` and `[T]` available on `String`,
+    /// `Box<str>`, `Vec<T>`, `Rc<[T]>` and other smart pointers.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// let name = "Hello".to_string();
+    /// name.should().as_deref().start_with("He");
+    ///
+    /// let items = vec![1, 2, 3];
+    /// items.should().as_deref().contain(&2);
+    /// ```
+    pub fn as_deref(&self) -> Should<'_, T::Target>
+    where
+        T: Deref,
+    {
+        Should::new(self.actual().deref(), self.path.clone())
+    }
+
+    /// Panics with `message`, prefixed with the breadcrumb path when there is one.
+    ///
+    /// Every built-in assertion fails through this method, so custom assertions
+    /// that use it report failures the same way.
+    ///
+    /// # Examples
+    /// ```should_panic
+    /// use shoulds::Shouldable;
+    ///
+    /// let value = 42;
+    /// value.should().fail("Expected the unexpected");
+    /// ```
+    #[track_caller]
+    pub fn fail(&self, message: impl Display) -> ! {
+        match &self.path {
+            Some(path) => panic!("{}: {}", path, message),
+            None => panic!("{}", message),
+        }
+    }
+
+    /// Fails with the message produced by `message` unless `condition` holds.
+    ///
+    /// This is the building block for custom assertions; see [`define_assertion!`](crate::define_assertion).
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// let value = 42;
+    /// value
+    ///     .should()
+    ///     .check(value % 2 == 0, || format!("Expected {} to be even", value));
+    /// ```
+    #[track_caller]
+    pub fn check<M: Display>(&self, condition: bool, message: impl FnOnce() -> M) {
+        if !condition {
+            self.fail(message());
+        }
     }
 }
 
@@ -24,12 +186,350 @@ impl<'a, T> Should<'a, T> {
 /// let x = 42;
 /// x.should().eq(&42);
 /// ```
-pub trait Shouldable<T> {
-    fn should(&self) -> Should<T>;
+pub trait Shouldable<T: ?Sized> {
+    fn should(&self) -> Should<'_, T>;
+
+    /// Mutably borrows the value, enabling assertions that mutate it.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// let mut items = vec![3, 1, 2].into_iter();
+    /// items.should_mut().actual_mut().next();
+    /// items.len().should().eq(&2);
+    /// ```
+    fn should_mut(&mut self) -> ShouldMut<'_, T>;
+
+    /// Takes ownership of the value, enabling assertions on temporaries and
+    /// assertions that consume or mutate the value.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// (1..=3).map(|x| x * 2).collect::<Vec<_>>().into_should().eq(&[2, 4, 6]);
+    /// ```
+    fn into_should<'a>(self) -> ShouldOwned<'a, T>
+    where
+        Self: Sized,
+        T: 'a;
+}
+
+impl<T: ?Sized> Shouldable<T> for T {
+    fn should(&self) -> Should<'_, T> {
+        Should::new(self, None)
+    }
+
+    fn should_mut(&mut self) -> ShouldMut<'_, T> {
+        ShouldMut(Should {
+            actual: Actual::Mutable(self),
+            path: None,
+        })
+    }
+
+    fn into_should<'a>(self) -> ShouldOwned<'a, T>
+    where
+        Self: Sized,
+        T: 'a,
+    {
+        ShouldOwned(ShouldMut(Should {
+            actual: Actual::Owned(Box::new(self)),
+            path: None,
+        }))
+    }
+}
+
+/// A [`Should`] that owns or mutably borrows the value under test, enabling
+/// assertions that consume or mutate it, such as those on iterators, streams,
+/// futures and readers.
+///
+/// Constructed using the [`Shouldable::should_mut`] method, or through the
+/// [`ShouldOwned`] returned by [`Shouldable::into_should`]. It dereferences to
+/// [`Should`], so all other assertions are available as well, while calling a
+/// mutating assertion on a borrowed value fails to compile:
+///
+/// ```compile_fail
+/// use shoulds::Shouldable;
+///
+/// let items = vec![1, 2].into_iter();
+/// items.should().yield_count(2);
+/// ```
+pub struct ShouldMut<'a, T: ?Sized>(Should<'a, T>);
+
+impl<'a, T: ?Sized> ShouldMut<'a, T> {
+    /// Returns a mutable reference to the underlying value.
+    ///
+    /// # Examples
+    /// ```
+    /// use shoulds::Shouldable;
+    ///
+    /// let mut should = (1..4).into_should();
+    /// should.actual_mut().next();
+    /// should.actual().start.should().eq(&2);
+    /// ```
+    pub fn actual_mut(&mut self) -> &mut T {
+        match &mut self.0.actual {
+            Actual::Mutable(actual) => actual,
+            Actual::Owned(actual) => actual,
+            Actual::Borrowed(_) => unreachable!("a `ShouldMut` never borrows immutably"),
+        }
+    }
+
+    /// Names the value under test so that failure messages start with `name`.
+    ///
+    /// See [`Should::named`].
+    pub fn named(self, name: impl Into<String>) -> Self {
+        ShouldMut(self.0.named(name))
+    }
+}
+
+impl<'a, T: ?Sized> Deref for ShouldMut<'a, T> {
+    type Target = Should<'a, T>;
+
+    fn deref(&self) -> &Should<'a, T> {
+        &self.0
+    }
+}
+
+impl<'a, T: ?Sized> DerefMut for ShouldMut<'a, T> {
+    fn deref_mut(&mut self) -> &mut Should<'a, T> {
+        &mut self.0
+    }
+}
+
+/// A [`ShouldMut`] that owns the value under test, enabling assertions that
+/// need to move it, such as calling a closure that is only `FnOnce`.
+///
+/// Constructed using the [`Shouldable::into_should`] method. It dereferences
+/// to [`ShouldMut`], so all other assertions are available as well.
+///
+/// ```compile_fail
+/// use shoulds::Shouldable;
+///
+/// let mut greet = {
+///     let name = String::from("Ada");
+///     move || drop(name)
+/// };
+/// greet.should_mut().not_panic();
+/// ```
+pub struct ShouldOwned<'a, T: ?Sized>(ShouldMut<'a, T>);
+
+impl<'a, T> ShouldOwned<'a, T> {
+    /// Returns the value under test and its breadcrumb path.
+    pub(crate) fn into_parts(self) -> (T, Option<String>) {
+        let Should { actual, path } = self.0 .0;
+
+        match actual {
+            Actual::Owned(actual) => (*actual, path),
+            Actual::Borrowed(_) | Actual::Mutable(_) => {
+                unreachable!("a `ShouldOwned` always owns its value")
+            }
+        }
+    }
 }
 
-impl<T> Shouldable<T> for T {
-    fn should(&self) -> Should<T> {
-        Should { actual: self }
+impl<'a, T: ?Sized> ShouldOwned<'a, T> {
+    /// Names the value under test so that failure messages start with `name`.
+    ///
+    /// See [`Should::named`].
+    pub fn named(self, name: impl Into<String>) -> Self {
+        ShouldOwned(self.0.named(name))
+    }
+}
+
+impl<'a, T: ?Sized> Deref for ShouldOwned<'a, T> {
+    type Target = ShouldMut<'a, T>;
+
+    fn deref(&self) -> &ShouldMut<'a, T> {
+        &self.0
+    }
+}
+
+impl<'a, T: ?Sized> DerefMut for ShouldOwned<'a, T> {
+    fn deref_mut(&mut self) -> &mut ShouldMut<'a, T> {
+        &mut self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Shouldable;
+
+    struct Address {
+        city: String,
+    }
+
+    struct User {
+        name: String,
+        address: Address,
+    }
+
+    fn user() -> User {
+        User {
+            name: "Kari".to_string(),
+            address: Address {
+                city: "Bergen".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn field_success_should_not_panic() {
+        let user = user();
+        user.should()
+            .field("address.city", |u| &u.address.city)
+            .eq("Bergen".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "user.address.city: Expected \"Bergen\" to equal to \"Oslo\"")]
+    fn field_fail_should_panic_with_path() {
+        let user = user();
+        user.should()
+            .named("user")
+            .field("address.city", |u| &u.address.city)
+            .eq("Oslo".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "user.address.city: Expected \"Bergen\" to equal to \"Oslo\"")]
+    fn nested_field_fail_should_panic_with_path() {
+        let user = user();
+        user.should()
+            .named("user")
+            .field("address", |u| &u.address)
+            .field("city", |a| &a.city)
+            .eq("Oslo".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "name: Expected \"Kari\" not to equal to \"Kari\"")]
+    fn field_without_name_should_use_field_path() {
+        let user = user();
+        user.should()
+            .field("name", |u| &u.name)
+            .ne("Kari".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "user: Expected Option to be Some, but got None")]
+    fn map_should_keep_path() {
+        let value: (Option<i32>, i32) = (None, 1);
+        value.should().named("user").map(|v| &v.0).be_some();
+    }
+
+    #[test]
+    fn path_should_track_breadcrumbs() {
+        let user = user();
+        let should = user.should().named("user");
+        let city = should.field("address", |u| &u.address);
+
+        city.path().should().eq(Some("user.address"));
+    }
+
+    #[test]
+    fn should_on_str_should_not_add_reference() {
+        let text: &str = "abc";
+        text.should().eq("abc");
+    }
+
+    #[test]
+    fn should_on_str_reference_should_keep_reference() {
+        let text: &str = "abc";
+        (&text).should().eq(&"abc");
+    }
+
+    #[test]
+    fn map_should_turn_arrays_into_slices() {
+        [1, 2, 3].should().map(|a| a.as_slice()).contain(&2);
+    }
+
+    #[test]
+    #[should_panic(expected = "shape: Expected area 4 to be at least 5")]
+    fn should_on_trait_object_should_support_custom_assertions() {
+        trait Shape {
+            fn area(&self) -> u32;
+        }
+
+        struct Square(u32);
+
+        impl Shape for Square {
+            fn area(&self) -> u32 {
+                self.0 * self.0
+            }
+        }
+
+        let shape: &dyn Shape = &Square(2);
+        let should = shape.should().named("shape");
+        let area = should.actual().area();
+
+        should.check(area >= 5, || {
+            format!("Expected area {} to be at least 5", area)
+        });
+    }
+
+    #[test]
+    fn should_on_trait_object_should_match_inline_snapshot() {
+        let value: &dyn std::fmt::Debug = &42;
+        value.should().match_inline_snapshot("42");
+    }
+
+    #[test]
+    #[should_panic(expected = "name: Expected \"Kari\" to start with \"O\"")]
+    fn as_deref_should_keep_path() {
+        let user = user();
+        user.should()
+            .field("name", |u| &u.name)
+            .as_deref()
+            .start_with("O");
+    }
+
+    #[test]
+    fn as_deref_should_support_smart_pointers() {
+        use std::rc::Rc;
+
+        let boxed: Box<str> = "abc".into();
+        boxed.should().as_deref().end_with("bc");
+
+        let shared: Rc<[i32]> = Rc::from(vec![1, 2, 3]);
+        shared.should().as_deref().have_len(3);
+    }
+
+    #[test]
+    fn into_should_success_should_not_panic() {
+        (1..=3)
+            .map(|x| x * 2)
+            .collect::<Vec<_>>()
+            .into_should()
+            .eq(&[2, 4, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "items: Expected [2, 4] to equal to [2, 4, 6]")]
+    fn into_should_fail_should_panic() {
+        vec![2, 4].into_should().named("items").eq(&[2, 4, 6]);
+    }
+
+    #[test]
+    fn into_should_should_allow_mutation() {
+        let mut should = vec![1, 2, 3].into_iter().into_should();
+        should.actual_mut().next();
+        should.actual().len().should().eq(&2);
+    }
+
+    #[test]
+    fn should_mut_should_allow_mutation() {
+        let mut items = vec![1, 2, 3].into_iter();
+        items.should_mut().actual_mut().next();
+        items.len().should().eq(&2);
+    }
+
+    #[test]
+    #[should_panic(expected = "items: Expected 2 to equal to 3")]
+    fn into_should_named_should_keep_path() {
+        let mut should = vec![1, 2, 3].into_iter().into_should().named("items");
+        should.actual_mut().next();
+        should.map(|items| &items.as_slice()[0]).eq(3);
     }
 }